@@ -1,7 +1,9 @@
 use crate::tools::{
-    base64_encoder::Base64Encoder, color_converter::ColorConverter, date_converter::DateConverter,
-    hash_generator::HashGenerator, number_base_converter::NumberBaseConverter,
-    password_generator::PasswordGenerator, qr_code_generator::QRCodeGenerator,
+    base64_encoder::Base64Encoder, chmod_calculator::ChmodCalculator,
+    color_converter::ColorConverter, cron_explainer::CronExplainer, date_converter::DateConverter,
+    hash_generator::HashGenerator, log_decoder::LogDecoder, network_calculator::NetworkCalculator,
+    number_base_converter::NumberBaseConverter, password_generator::PasswordGenerator,
+    qr_code_generator::QRCodeGenerator, unicode_inspector::UnicodeInspector,
     uuid_generator::UuidGenerator,
 };
 
@@ -9,12 +11,17 @@ use crate::tools::{
 #[derive(PartialEq)]
 pub enum Tool {
     Base64Encoder,
+    ChmodCalculator,
     ColorConverter,
+    CronExplainer,
     DateConverter,
     HashGenerator,
+    LogDecoder,
+    NetworkCalculator,
     NumberBaseConverter,
     PasswordGenerator,
     QRCodeGenerator,
+    UnicodeInspector,
     UuidGenerator,
 }
 
@@ -22,20 +29,32 @@ pub enum Tool {
 pub struct App {
     /// The currently selected tool.
     pub current_tool: Tool,
+    /// Whether the terminal supports 24-bit "truecolor" colors.
+    pub truecolor: bool,
     /// The Base64 encoder tool.
     pub base64_encoder: Base64Encoder,
+    /// The chmod calculator tool.
+    pub chmod_calculator: ChmodCalculator,
     /// The color converter tool.
     pub color_converter: ColorConverter,
+    /// The cron expression explainer tool.
+    pub cron_explainer: CronExplainer,
     /// The date converter tool.
     pub date_converter: DateConverter,
     /// The hash generator tool.
     pub hash_generator: HashGenerator,
+    /// The log timestamp decoder tool.
+    pub log_decoder: LogDecoder,
+    /// The network calculator tool.
+    pub network_calculator: NetworkCalculator,
     /// The number base converter tool.
     pub number_base_converter: NumberBaseConverter,
     /// The password generator tool.
     pub password_generator: PasswordGenerator,
     /// The QR code generator tool.
     pub qr_code_generator: QRCodeGenerator,
+    /// The Unicode inspector tool.
+    pub unicode_inspector: UnicodeInspector,
     /// The UUID generator tool.
     pub uuid_generator: UuidGenerator,
 }
@@ -45,14 +64,28 @@ impl App {
     pub fn new() -> App {
         App {
             current_tool: Tool::Base64Encoder, // Default to Base64Encoder tool
+            truecolor: supports_truecolor(),
             base64_encoder: Base64Encoder::new(),
+            chmod_calculator: ChmodCalculator::new(),
             color_converter: ColorConverter::new(),
+            cron_explainer: CronExplainer::new(),
             date_converter: DateConverter::new(),
             hash_generator: HashGenerator::new(),
+            log_decoder: LogDecoder::new(),
+            network_calculator: NetworkCalculator::new(),
             number_base_converter: NumberBaseConverter::new(),
             password_generator: PasswordGenerator::new(),
             qr_code_generator: QRCodeGenerator::new(),
+            unicode_inspector: UnicodeInspector::new(),
             uuid_generator: UuidGenerator::new(),
         }
     }
 }
+
+/// Detects truecolor support from the `COLORTERM` environment variable, which terminals
+/// with 24-bit colors set to `truecolor` or `24bit`.
+fn supports_truecolor() -> bool {
+    std::env::var("COLORTERM")
+        .map(|value| value == "truecolor" || value == "24bit")
+        .unwrap_or(false)
+}
@@ -25,11 +25,16 @@ fn main() -> Result<(), io::Error> {
 
     // Initialize text areas for each tool, these will be used to input and display data
     let mut base64_converter_textarea = TextArea::default();
+    let mut chmod_calculator_textarea = TextArea::default();
     let mut color_converter_textarea = TextArea::default();
+    let mut cron_explainer_textarea = TextArea::default();
     let mut date_converter_textarea = TextArea::default();
     let mut hash_generator_textarea = TextArea::default();
+    let mut log_decoder_textarea = TextArea::default();
+    let mut network_calculator_textarea = TextArea::default();
     let mut number_base_converter_textarea = TextArea::default();
     let mut qr_code_generator_textarea = TextArea::default();
+    let mut unicode_inspector_textarea = TextArea::default();
 
     // Create a new instance of the App, the main application structure.
     let app = App::new(); 
@@ -40,11 +45,16 @@ fn main() -> Result<(), io::Error> {
         &mut terminal,
         app, // remove &mut
         &mut base64_converter_textarea,
+        &mut chmod_calculator_textarea,
         &mut color_converter_textarea,
+        &mut cron_explainer_textarea,
         &mut date_converter_textarea,
         &mut hash_generator_textarea,
+        &mut log_decoder_textarea,
+        &mut network_calculator_textarea,
         &mut number_base_converter_textarea,
         &mut qr_code_generator_textarea,
+        &mut unicode_inspector_textarea,
     );
 
     // Disable raw mode & returns to normal state.
@@ -0,0 +1,389 @@
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::Path;
+
+/// The setuid bit.
+pub const SETUID: u32 = 0o4000;
+/// The setgid bit.
+pub const SETGID: u32 = 0o2000;
+/// The sticky bit.
+pub const STICKY: u32 = 0o1000;
+
+/// The permission classes in display order, with their name, symbol and bit shift.
+const CLASSES: [(&str, char, u32); 3] = [("Owner", 'u', 6), ("Group", 'g', 3), ("Other", 'o', 0)];
+
+/// Struct to convert Unix file permissions between octal, symbolic and grid forms.
+pub struct ChmodCalculator {
+    /// The input mode or symbolic expression.
+    pub input: String,
+    /// The current permission bits, including setuid, setgid and sticky.
+    pub mode: u32,
+    /// The mode symbolic expressions are applied to, i.e. the last octal or symbolic mode.
+    base_mode: u32,
+    /// How the input was interpreted.
+    pub input_format: String,
+    /// The mode in octal form, e.g. `0755`.
+    pub octal: String,
+    /// The mode in symbolic form, e.g. `rwxr-xr-x`.
+    pub symbolic: String,
+    /// The mode as shown by `ls -l`, e.g. `-rwxr-xr-x`.
+    pub ls_format: String,
+    /// Equivalent `chmod` commands.
+    pub chmod_commands: String,
+    /// A plain English description of each permission class.
+    pub description: String,
+    /// A potential message for tools export.
+    pub tools_export_message: Option<String>,
+}
+
+impl ChmodCalculator {
+    /// Creates a new instance of `ChmodCalculator`.
+    pub fn new() -> Self {
+        ChmodCalculator {
+            input: String::new(),
+            mode: 0,
+            base_mode: 0,
+            input_format: String::new(),
+            octal: String::new(),
+            symbolic: String::new(),
+            ls_format: String::new(),
+            chmod_commands: String::new(),
+            description: String::new(),
+            tools_export_message: None,
+        }
+    }
+
+    /// Parses the `input` and converts the resulting mode to all representations.
+    ///
+    /// The input can be an octal mode (`755`, `4755`), a symbolic string (`rwxr-xr-x`),
+    /// a symbolic expression applied to the current mode (`u+x,go-w`), or a base mode
+    /// followed by an expression (`644 u+x,go-w`).
+    pub fn convert_all(&mut self) {
+        let input = self.input.trim();
+        if input.is_empty() {
+            self.set_all_to("");
+            return;
+        }
+
+        match parse_input(input, self.base_mode) {
+            Ok((mode, base_mode, format)) => {
+                self.mode = mode;
+                self.base_mode = base_mode;
+                self.input_format = format;
+                self.update_outputs();
+            }
+            Err(err) => self.set_all_to(&err),
+        }
+    }
+
+    /// Toggles the given permission bit, as done from the permission grid.
+    pub fn toggle_bit(&mut self, bit: u32) {
+        self.mode ^= bit;
+        self.base_mode = self.mode;
+        self.input_format = "Permission grid".to_string();
+        self.update_outputs();
+    }
+
+    /// Refreshes all representations from `mode`.
+    fn update_outputs(&mut self) {
+        self.octal = format!("{:04o}", self.mode);
+        self.symbolic = symbolic_string(self.mode);
+        self.ls_format = format!("-{}", self.symbolic);
+        self.chmod_commands = format!(
+            "chmod {:o} <file>\nchmod {} <file>",
+            self.mode,
+            symbolic_assignment(self.mode)
+        );
+        self.description = describe_mode(self.mode);
+    }
+
+    /// Sets all output fields to the provided error message.
+    fn set_all_to(&mut self, message: &str) {
+        self.input_format = message.to_string();
+        self.octal = message.to_string();
+        self.symbolic = message.to_string();
+        self.ls_format = message.to_string();
+        self.chmod_commands = message.to_string();
+        self.description = message.to_string();
+    }
+
+    /// Exports the input and all representations of the mode to a file.
+    pub fn write_to_file(&self) -> std::io::Result<()> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new("export/chmod.txt");
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // Write the input and each representation to the file.
+        writeln!(file, "Input: {}", self.input)?;
+        writeln!(file, "Interpreted As: {}", self.input_format)?;
+        writeln!(file, "Octal: {}", self.octal)?;
+        writeln!(file, "Symbolic: {}", self.symbolic)?;
+        writeln!(file, "ls -l: {}", self.ls_format)?;
+        writeln!(file, "\nCommands:\n{}", self.chmod_commands)?;
+        writeln!(file, "\nPermissions:\n{}", self.description)?;
+
+        // Return Ok(()) to indicate success.
+        Ok(())
+    }
+}
+
+/// Parses any supported input form, returning the mode, the base mode for later
+/// expressions and how the input was interpreted.
+fn parse_input(input: &str, base_mode: u32) -> Result<(u32, u32, String), String> {
+    if let Some(mode) = parse_octal(input) {
+        return Ok((mode, mode, "Octal mode".to_string()));
+    }
+    if let Some(mode) = parse_symbolic_string(input) {
+        return Ok((mode, mode, "Symbolic string".to_string()));
+    }
+
+    // A base mode followed by an expression, e.g. `644 u+x,go-w`.
+    if let Some((base, expression)) = input.split_once(char::is_whitespace) {
+        let base_mode = parse_octal(base)
+            .or_else(|| parse_symbolic_string(base))
+            .ok_or_else(|| format!("Invalid base mode '{}'", base))?;
+        let mode = apply_expression(base_mode, expression.trim())?;
+        return Ok((
+            mode,
+            base_mode,
+            format!("Expression applied to {:04o}", base_mode),
+        ));
+    }
+
+    // A bare expression is applied to the last octal or symbolic mode.
+    let mode = apply_expression(base_mode, input)?;
+    Ok((
+        mode,
+        base_mode,
+        format!("Expression applied to {:04o}", base_mode),
+    ))
+}
+
+/// Parses an octal mode of one to four digits, e.g. `755` or `4755`.
+fn parse_octal(input: &str) -> Option<u32> {
+    if input.is_empty() || input.len() > 5 || !input.chars().all(|c| ('0'..='7').contains(&c)) {
+        return None;
+    }
+    // A leading zero is allowed in front of four digits, e.g. `00755`.
+    let mode = u32::from_str_radix(input, 8).ok()?;
+    (mode <= 0o7777).then_some(mode)
+}
+
+/// Parses a symbolic string such as `rwxr-xr-x` or `drwsr-xr-t`.
+fn parse_symbolic_string(input: &str) -> Option<u32> {
+    let chars: Vec<char> = input.chars().collect();
+    // Skip the file type character shown by `ls -l`.
+    let chars = match chars.len() {
+        9 => &chars[..],
+        10 if "-dlcbps".contains(chars[0]) => &chars[1..],
+        _ => return None,
+    };
+
+    let mut mode = 0;
+    for (index, (_, _, shift)) in CLASSES.iter().enumerate() {
+        let triplet = &chars[index * 3..index * 3 + 3];
+        match triplet[0] {
+            'r' => mode |= 0o4 << shift,
+            '-' => {}
+            _ => return None,
+        }
+        match triplet[1] {
+            'w' => mode |= 0o2 << shift,
+            '-' => {}
+            _ => return None,
+        }
+        // The execute position also carries the special bit of the class.
+        let (special, lower, upper) = match index {
+            0 => (SETUID, 's', 'S'),
+            1 => (SETGID, 's', 'S'),
+            _ => (STICKY, 't', 'T'),
+        };
+        match triplet[2] {
+            'x' => mode |= 0o1 << shift,
+            '-' => {}
+            c if c == lower => mode |= special | (0o1 << shift),
+            c if c == upper => mode |= special,
+            _ => return None,
+        }
+    }
+    Some(mode)
+}
+
+/// Applies a symbolic expression such as `u+x,go-w` or `a=r,u+w` to a mode.
+fn apply_expression(mode: u32, expression: &str) -> Result<u32, String> {
+    let mut mode = mode;
+
+    for clause in expression.split(',') {
+        let invalid = |reason: String| format!("Invalid expression '{}': {}", clause, reason);
+
+        // Read the classes the clause applies to; none means all.
+        let who_end = clause
+            .find(|c| !"ugoa".contains(c))
+            .ok_or_else(|| invalid("missing operator (+, - or =)".to_string()))?;
+        let who = &clause[..who_end];
+        let (mut who_mask, mut special_mask) = (0, 0);
+        for c in who.chars() {
+            let (mask, special) = match c {
+                'u' => (0o700, SETUID),
+                'g' => (0o070, SETGID),
+                'o' => (0o007, STICKY),
+                _ => (0o777, SETUID | SETGID | STICKY),
+            };
+            who_mask |= mask;
+            special_mask |= special;
+        }
+        if who.is_empty() {
+            who_mask = 0o777;
+            special_mask = SETUID | SETGID | STICKY;
+        }
+
+        // Apply each operator and its permissions in turn, e.g. `u-w+x`.
+        let mut rest = &clause[who_end..];
+        while let Some(op) = rest.chars().next() {
+            if !"+-=".contains(op) {
+                return Err(invalid(format!("unexpected '{}'", op)));
+            }
+            rest = &rest[1..];
+            let perms_end = rest.find(['+', '-', '=']).unwrap_or(rest.len());
+            let perms = &rest[..perms_end];
+            rest = &rest[perms_end..];
+
+            let mut bits = 0;
+            for c in perms.chars() {
+                bits |= match c {
+                    'r' => 0o444 & who_mask,
+                    'w' => 0o222 & who_mask,
+                    'x' => 0o111 & who_mask,
+                    // `X` sets execute only if some class can already execute.
+                    'X' if mode & 0o111 != 0 => 0o111 & who_mask,
+                    'X' => 0,
+                    's' => (SETUID | SETGID) & special_mask,
+                    't' => STICKY & special_mask,
+                    // Copy the permissions of another class, e.g. `g=u`.
+                    'u' | 'g' | 'o' => {
+                        if perms.len() != 1 {
+                            return Err(invalid(format!(
+                                "'{}' must be used on its own, e.g. g={}",
+                                c, c
+                            )));
+                        }
+                        let shift = CLASSES.iter().find(|class| class.1 == c).unwrap().2;
+                        (((mode >> shift) & 0o7) * 0o111) & who_mask
+                    }
+                    _ => return Err(invalid(format!("unknown permission '{}'", c))),
+                };
+            }
+
+            match op {
+                '+' => mode |= bits,
+                '-' => mode &= !bits,
+                _ => {
+                    // `=` clears the classes' permissions before setting them.
+                    mode &= !(who_mask | special_mask);
+                    mode |= bits;
+                }
+            }
+        }
+    }
+
+    Ok(mode)
+}
+
+/// Formats a mode as a symbolic string such as `rwsr-xr-t`.
+fn symbolic_string(mode: u32) -> String {
+    let mut symbolic = String::new();
+    for (index, (_, _, shift)) in CLASSES.iter().enumerate() {
+        let bits = (mode >> shift) & 0o7;
+        symbolic.push(if bits & 0o4 != 0 { 'r' } else { '-' });
+        symbolic.push(if bits & 0o2 != 0 { 'w' } else { '-' });
+        let (special, lower, upper) = match index {
+            0 => (SETUID, 's', 'S'),
+            1 => (SETGID, 's', 'S'),
+            _ => (STICKY, 't', 'T'),
+        };
+        symbolic.push(match (mode & special != 0, bits & 0o1 != 0) {
+            (true, true) => lower,
+            (true, false) => upper,
+            (false, true) => 'x',
+            (false, false) => '-',
+        });
+    }
+    symbolic
+}
+
+/// Formats a mode as a symbolic `chmod` assignment such as `u=rwx,g=rx,o=rx`.
+fn symbolic_assignment(mode: u32) -> String {
+    let mut clauses: Vec<String> = CLASSES
+        .iter()
+        .map(|(_, symbol, shift)| {
+            let bits = (mode >> shift) & 0o7;
+            let mut clause = format!("{}=", symbol);
+            if bits & 0o4 != 0 {
+                clause.push('r');
+            }
+            if bits & 0o2 != 0 {
+                clause.push('w');
+            }
+            if bits & 0o1 != 0 {
+                clause.push('x');
+            }
+            clause
+        })
+        .collect();
+
+    if mode & SETUID != 0 {
+        clauses.push("u+s".to_string());
+    }
+    if mode & SETGID != 0 {
+        clauses.push("g+s".to_string());
+    }
+    if mode & STICKY != 0 {
+        clauses.push("+t".to_string());
+    }
+    clauses.join(",")
+}
+
+/// Describes each permission class and special bit in plain English.
+fn describe_mode(mode: u32) -> String {
+    let mut lines: Vec<String> = CLASSES
+        .iter()
+        .map(|(name, symbol, shift)| {
+            let bits = (mode >> shift) & 0o7;
+            let mut permissions = Vec::new();
+            if bits & 0o4 != 0 {
+                permissions.push("read");
+            }
+            if bits & 0o2 != 0 {
+                permissions.push("write");
+            }
+            if bits & 0o1 != 0 {
+                permissions.push("execute");
+            }
+            if permissions.is_empty() {
+                permissions.push("no access");
+            }
+            format!("{} ({}): {}", name, symbol, permissions.join(", "))
+        })
+        .collect();
+
+    let mut special = Vec::new();
+    if mode & SETUID != 0 {
+        special.push("setuid (runs as the file owner)");
+    }
+    if mode & SETGID != 0 {
+        special.push("setgid (runs as the file group)");
+    }
+    if mode & STICKY != 0 {
+        special.push("sticky (only owners can delete entries)");
+    }
+    if special.is_empty() {
+        special.push("none");
+    }
+    lines.push(format!("Special: {}", special.join(", ")));
+    lines.join("\n")
+}
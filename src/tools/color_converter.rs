@@ -1,11 +1,232 @@
+use image::imageops::FilterType;
 use std::fs::{create_dir_all, File};
 use std::io::Write;
 use std::path::Path;
+use std::time::SystemTime;
+
+/// The format bare, comma separated color values are read in.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ColorFormat {
+    /// Detects the format from the number of values and their units.
+    Auto,
+    Cmyk,
+    Hex,
+    Hsl,
+    Rgb,
+}
+
+impl ColorFormat {
+    /// Returns the name of the format, as shown in the UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorFormat::Auto => "Auto Detect",
+            ColorFormat::Cmyk => "CMYK",
+            ColorFormat::Hex => "HEX",
+            ColorFormat::Hsl => "HSL",
+            ColorFormat::Rgb => "RGB",
+        }
+    }
+}
+
+/// What the color tool does with its input.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ColorMode {
+    /// Converts a color to all supported formats.
+    Convert,
+    /// Checks the WCAG contrast between a text color and a background color.
+    Contrast,
+    /// Generates shades and color schemes from a base color.
+    Palette,
+    /// Simulates how colors appear with color vision deficiencies.
+    ColorBlindness,
+    /// Mixes two colors, and interpolates a gradient between them.
+    Mix,
+    /// Extracts the dominant colors of an image file.
+    Image,
+}
+
+impl ColorMode {
+    /// Returns the name of the mode, as shown in the UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorMode::Convert => "Convert",
+            ColorMode::Contrast => "Contrast Check",
+            ColorMode::Palette => "Palette",
+            ColorMode::ColorBlindness => "Color Blindness",
+            ColorMode::Mix => "Mix and Gradient",
+            ColorMode::Image => "Image Palette",
+        }
+    }
+}
+
+/// The formats a palette can be exported in.
+#[derive(Clone, Copy, PartialEq)]
+pub enum PaletteFormat {
+    Css,
+    Scss,
+    Tailwind,
+    Json,
+}
+
+impl PaletteFormat {
+    /// Returns the name of the format, as shown in the UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PaletteFormat::Css => "CSS Custom Properties",
+            PaletteFormat::Scss => "SCSS Variables",
+            PaletteFormat::Tailwind => "Tailwind Config",
+            PaletteFormat::Json => "JSON",
+        }
+    }
+
+    /// Returns the path the palette is exported to in this format.
+    pub fn path(&self) -> &'static str {
+        match self {
+            PaletteFormat::Css => "export/palette.css",
+            PaletteFormat::Scss => "export/palette.scss",
+            PaletteFormat::Tailwind => "export/tailwind.config.js",
+            PaletteFormat::Json => "export/palette.json",
+        }
+    }
+}
+
+/// The color spaces two colors can be mixed in.
+#[derive(Clone, Copy, PartialEq)]
+pub enum MixSpace {
+    Srgb,
+    LinearRgb,
+    Hsl,
+    Oklab,
+}
+
+impl MixSpace {
+    /// Returns the name of the color space, as shown in the UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            MixSpace::Srgb => "sRGB",
+            MixSpace::LinearRgb => "Linear RGB",
+            MixSpace::Hsl => "HSL",
+            MixSpace::Oklab => "OKLab",
+        }
+    }
+}
+
+/// Two colors, a blend of them and a gradient between them.
+pub struct Mix {
+    /// The color the blend and gradient start from.
+    pub from: Color,
+    /// The color the blend and gradient go to.
+    pub to: Color,
+    /// The blend of both colors at the mix ratio.
+    pub blend: Color,
+    /// The gradient from the first to the second color, both included.
+    pub gradient: Vec<Color>,
+}
+
+/// A dominant color of an image.
+pub struct DominantColor {
+    /// The average color of the pixels it stands for.
+    pub color: Color,
+    /// The share of the image pixels it stands for, from 0 to 1.
+    pub share: f32,
+}
+
+/// The color vision deficiencies colors can be simulated with.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Deficiency {
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+    Achromatopsia,
+}
+
+impl Deficiency {
+    /// All deficiencies, in the order they are shown.
+    pub const ALL: [Deficiency; 4] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+        Deficiency::Achromatopsia,
+    ];
+
+    /// Returns the name of the deficiency, as shown in the UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Deficiency::Protanopia => "Protanopia",
+            Deficiency::Deuteranopia => "Deuteranopia",
+            Deficiency::Tritanopia => "Tritanopia",
+            Deficiency::Achromatopsia => "Achromatopsia",
+        }
+    }
+
+    /// Returns a short description of the deficiency.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Deficiency::Protanopia => "no red cones",
+            Deficiency::Deuteranopia => "no green cones",
+            Deficiency::Tritanopia => "no blue cones",
+            Deficiency::Achromatopsia => "no color vision",
+        }
+    }
+
+    /// Simulates how a color appears with the deficiency. Dichromacies use the full severity
+    /// matrices of Machado, Oliveira and Fernandes (2009) in linear RGB, and achromatopsia
+    /// keeps only the luminance.
+    pub fn simulate(&self, color: &Color) -> Color {
+        let linear = linear_rgb(color);
+        let simulated = match self {
+            Deficiency::Protanopia => multiply(
+                &[
+                    [0.152_286, 1.052_583, -0.204_868],
+                    [0.114_503, 0.786_281, 0.099_216],
+                    [-0.003_882, -0.048_116, 1.051_998],
+                ],
+                linear,
+            ),
+            Deficiency::Deuteranopia => multiply(
+                &[
+                    [0.367_322, 0.860_646, -0.227_968],
+                    [0.280_085, 0.672_501, 0.047_413],
+                    [-0.011_82, 0.042_94, 0.968_881],
+                ],
+                linear,
+            ),
+            Deficiency::Tritanopia => multiply(
+                &[
+                    [1.255_528, -0.076_749, -0.178_779],
+                    [-0.078_411, 0.930_809, 0.147_602],
+                    [0.004_733, 0.691_367, 0.303_9],
+                ],
+                linear,
+            ),
+            Deficiency::Achromatopsia => [relative_luminance(color); 3],
+        };
+        Color {
+            clipped: false,
+            ..linear_to_color(simulated, color.a)
+        }
+    }
+}
+
+/// Shades and color schemes generated from a base color.
+pub struct Palette {
+    /// Tints and shades on a Tailwind-style scale, from 50 (lightest) to 900 (darkest).
+    pub shades: Vec<(u16, Color)>,
+    /// The complementary, triadic, analogous and split-complementary schemes, each starting
+    /// with the base color.
+    pub schemes: Vec<(&'static str, Vec<Color>)>,
+}
+
+/// The path of an image file and its modification time, when it could be read.
+type ImageKey = (String, Option<SystemTime>);
 
 /// A struct that encapsulates color conversion functionality.
 pub struct ColorConverter {
-    /// The input color string.
+    /// The input color string. A second line holds the second color, e.g. the background
+    /// of a contrast check.
     pub input: String,
+    /// What is done with the input.
+    pub mode: ColorMode,
     /// The converted CMYK color string.
     pub cmyk: String,
     /// The converted RGB color string.
@@ -14,6 +235,53 @@ pub struct ColorConverter {
     pub hex: String,
     /// The converted HSL color string.
     pub hsl: String,
+    /// The converted HSV (HSB) color string.
+    pub hsv: String,
+    /// The converted HWB color string.
+    pub hwb: String,
+    /// The converted CIE XYZ (D65) color string.
+    pub xyz: String,
+    /// The converted CIE Lab color string.
+    pub lab: String,
+    /// The converted CIE LCH color string.
+    pub lch: String,
+    /// The converted OKLab color string.
+    pub oklab: String,
+    /// The converted OKLCH color string.
+    pub oklch: String,
+    /// Whether the input fits in the sRGB gamut, or was clipped to fit it.
+    pub gamut: String,
+    /// The nearest CSS named color.
+    pub named_color: String,
+    /// The format bare values are read in.
+    pub format: ColorFormat,
+    /// The parsed color, used to render the preview.
+    pub color: Option<Color>,
+    /// The HSL reading of input that is also valid RGB, shown next to the RGB reading.
+    pub ambiguous_hsl: Option<Color>,
+    /// The contrast between the first color, as text, and the second color, as background.
+    pub contrast: Result<ContrastCheck, String>,
+    /// The shades and color schemes generated from the color.
+    pub palette: Option<Palette>,
+    /// The format the palette is exported in.
+    pub palette_format: PaletteFormat,
+    /// The share of the second color in the blend, in percent.
+    pub mix_ratio: u8,
+    /// The number of colors in the gradient.
+    pub gradient_steps: usize,
+    /// The color space colors are mixed in.
+    pub mix_space: MixSpace,
+    /// The blend and gradient of the first and second colors.
+    pub mix: Result<Mix, String>,
+    /// The number of dominant colors extracted from an image.
+    pub image_color_count: usize,
+    /// The dominant colors of the image, from the most to the least common.
+    pub image_colors: Result<Vec<DominantColor>, String>,
+    /// The path, modification time and sampled pixels of the last image read, so changing
+    /// the number of colors doesn't read it again.
+    image_pixels: Option<(ImageKey, Vec<[u8; 3]>)>,
+    /// The image and number of colors `image_colors` were extracted with.
+    image_extracted: Option<(ImageKey, usize)>,
     /// A potential message for tools export.
     pub tools_export_message: Option<String>,
 }
@@ -23,56 +291,477 @@ impl ColorConverter {
     pub fn new() -> Self {
         ColorConverter {
             input: String::new(),
+            mode: ColorMode::Convert,
             cmyk: String::new(),
             rgb: String::new(),
             hex: String::new(),
             hsl: String::new(),
+            hsv: String::new(),
+            hwb: String::new(),
+            xyz: String::new(),
+            lab: String::new(),
+            lch: String::new(),
+            oklab: String::new(),
+            oklch: String::new(),
+            gamut: String::new(),
+            named_color: String::new(),
+            format: ColorFormat::Auto,
+            color: None,
+            ambiguous_hsl: None,
+            contrast: Err(String::new()),
+            palette: None,
+            palette_format: PaletteFormat::Css,
+            mix_ratio: 50,
+            gradient_steps: 5,
+            mix_space: MixSpace::Oklab,
+            mix: Err(String::new()),
+            image_color_count: 6,
+            image_colors: Err(String::new()),
+            image_pixels: None,
+            image_extracted: None,
             tools_export_message: None,
         }
     }
 
     /// Converts the `input` color to CMYK format.
     pub fn convert_to_cmyk(&mut self) {
-        if let Some(color) = parse_color(&self.input) {
-            self.cmyk = convert_to_cmyk(&color);
-        } else {
-            self.cmyk = "Invalid color format".to_string();
-        }
+        self.cmyk = match parse_color(self.first_line(), self.format) {
+            Ok(color) => convert_to_cmyk(&color),
+            Err(err) => err,
+        };
     }
 
     /// Converts the `input` color to RGB format.
     pub fn convert_to_rgb(&mut self) {
-        if let Some(color) = parse_color(&self.input) {
-            self.rgb = convert_to_rgb(&color);
-        } else {
-            self.rgb = "Invalid color format".to_string();
-        }
+        self.rgb = match parse_color(self.first_line(), self.format) {
+            Ok(color) => convert_to_rgb(&color),
+            Err(err) => err,
+        };
     }
 
     /// Converts the `input` color to HEX format.
     pub fn convert_to_hex(&mut self) {
-        if let Some(color) = parse_color(&self.input) {
-            self.hex = convert_to_hex(&color);
-        } else {
-            self.hex = "Invalid color format".to_string();
-        }
+        self.hex = match parse_color(self.first_line(), self.format) {
+            Ok(color) => convert_to_hex(&color),
+            Err(err) => err,
+        };
     }
 
     /// Converts the `input` color to HSL format.
     pub fn convert_to_hsl(&mut self) {
-        if let Some(color) = parse_color(&self.input) {
-            self.hsl = convert_to_hsl(&color);
-        } else {
-            self.hsl = "Invalid color format".to_string();
+        self.hsl = match parse_color(self.first_line(), self.format) {
+            Ok(color) => convert_to_hsl(&color),
+            Err(err) => err,
+        };
+    }
+
+    /// Converts the `input` color to the HSV, HWB, XYZ, Lab, LCH, OKLab and OKLCH color spaces.
+    pub fn convert_to_color_spaces(&mut self) {
+        match parse_color(self.first_line(), self.format) {
+            Ok(color) => {
+                self.hsv = convert_to_hsv(&color);
+                self.hwb = convert_to_hwb(&color);
+                self.xyz = convert_to_xyz(&color);
+                self.lab = convert_to_lab(&color);
+                self.lch = convert_to_lch(&color);
+                self.oklab = convert_to_oklab(&color);
+                self.oklch = convert_to_oklch(&color);
+                self.gamut = if color.clipped {
+                    "Outside the sRGB gamut, clipped to the nearest displayable color".to_string()
+                } else {
+                    "Within the sRGB gamut".to_string()
+                };
+            }
+            Err(err) => {
+                for output in [
+                    &mut self.hsv,
+                    &mut self.hwb,
+                    &mut self.xyz,
+                    &mut self.lab,
+                    &mut self.lch,
+                    &mut self.oklab,
+                    &mut self.oklch,
+                    &mut self.gamut,
+                ] {
+                    *output = err.clone();
+                }
+            }
+        }
+    }
+
+    /// Returns the first line of the input, the color being converted.
+    fn first_line(&self) -> &str {
+        self.input.lines().next().unwrap_or_default()
+    }
+
+    /// Parses the first and second colors, naming each in errors, e.g. `Text color`.
+    fn parse_both_colors(
+        &self,
+        first_name: &str,
+        second_name: &str,
+    ) -> Result<(Color, Color), String> {
+        let first = parse_color(self.first_line(), self.format)
+            .map_err(|err| format!("{}: {}", first_name, err))?;
+        let second = match self.input.lines().nth(1) {
+            Some(line) if !line.trim().is_empty() => {
+                parse_color(line, self.format).map_err(|err| format!("{}: {}", second_name, err))?
+            }
+            _ => {
+                return Err(format!(
+                    "Enter a {} on the second line",
+                    second_name.to_ascii_lowercase()
+                ))
+            }
+        };
+        Ok((first, second))
+    }
+
+    /// Checks the contrast between the first color, as text, and the second color, as background.
+    pub fn check_contrast(&mut self) {
+        self.contrast = self
+            .parse_both_colors("Text color", "Background color")
+            .map(|(foreground, background)| ContrastCheck::new(foreground, background));
+    }
+
+    /// Blends the first and second colors at the mix ratio, and interpolates a gradient between them.
+    pub fn mix_colors(&mut self) {
+        self.mix = self
+            .parse_both_colors("First color", "Second color")
+            .map(|(from, to)| {
+                let space = self.mix_space;
+                let steps = self.gradient_steps;
+                Mix {
+                    from,
+                    to,
+                    blend: interpolate(&from, &to, self.mix_ratio as f32 / 100.0, space),
+                    gradient: (0..steps)
+                        .map(|i| interpolate(&from, &to, i as f32 / (steps - 1) as f32, space))
+                        .collect(),
+                }
+            });
+    }
+
+    /// Increases the share of the second color in the blend by 5%.
+    pub fn increase_mix_ratio(&mut self) {
+        self.mix_ratio = (self.mix_ratio + 5).min(100);
+    }
+
+    /// Decreases the share of the second color in the blend by 5%.
+    pub fn decrease_mix_ratio(&mut self) {
+        self.mix_ratio = self.mix_ratio.saturating_sub(5);
+    }
+
+    /// Increases the number of colors in the gradient, up to 32.
+    pub fn increase_gradient_steps(&mut self) {
+        self.gradient_steps = (self.gradient_steps + 1).min(32);
+    }
+
+    /// Decreases the number of colors in the gradient, down to 2.
+    pub fn decrease_gradient_steps(&mut self) {
+        self.gradient_steps = (self.gradient_steps - 1).max(2);
+    }
+
+    /// Cycles the color space colors are mixed in.
+    pub fn cycle_mix_space(&mut self) {
+        self.mix_space = match self.mix_space {
+            MixSpace::Srgb => MixSpace::LinearRgb,
+            MixSpace::LinearRgb => MixSpace::Hsl,
+            MixSpace::Hsl => MixSpace::Oklab,
+            MixSpace::Oklab => MixSpace::Srgb,
+        };
+    }
+
+    /// Formats the gradient as a CSS `linear-gradient()`, with a stop for each color.
+    pub fn gradient_css(&self) -> String {
+        let gradient = match &self.mix {
+            Ok(mix) => &mix.gradient,
+            Err(_) => return String::new(),
+        };
+        let last = (gradient.len() - 1) as f32;
+        let stops: Vec<String> = gradient
+            .iter()
+            .enumerate()
+            .map(|(i, color)| {
+                // Stops are rounded to two decimals, without trailing zeros, e.g. `33.33%`.
+                let stop = format!("{:.2}", i as f32 / last * 100.0);
+                let stop = stop.trim_end_matches('0').trim_end_matches('.');
+                format!("{} {}%", convert_to_hex(color), stop)
+            })
+            .collect();
+        format!("linear-gradient(to right, {})", stops.join(", "))
+    }
+
+    /// Exports the blend and gradient to a file, as a CSS `linear-gradient()` and a list of HEX codes.
+    pub fn export_gradient(&self) -> std::io::Result<()> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new("export/gradient.txt");
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // Write the colors, the blend and the gradient to the file.
+        match &self.mix {
+            Ok(mix) => {
+                writeln!(file, "From: {}", convert_to_hex(&mix.from))?;
+                writeln!(file, "To: {}", convert_to_hex(&mix.to))?;
+                writeln!(file, "Color Space: {}", self.mix_space.label())?;
+                writeln!(
+                    file,
+                    "Blend ({}%): {}",
+                    self.mix_ratio,
+                    convert_to_hex(&mix.blend)
+                )?;
+                writeln!(file)?;
+                writeln!(file, "CSS: {};", self.gradient_css())?;
+                writeln!(file)?;
+                writeln!(file, "Gradient ({} Steps):", mix.gradient.len())?;
+                for color in &mix.gradient {
+                    writeln!(file, "{}", convert_to_hex(color))?;
+                }
+            }
+            Err(err) => writeln!(file, "{}", err)?,
+        }
+
+        Ok(())
+    }
+
+    /// Extracts the dominant colors of the image whose path is on the first line.
+    pub fn extract_image_colors(&mut self) {
+        // Paths dropped into a terminal are often quoted.
+        let path = self
+            .first_line()
+            .trim()
+            .trim_matches(|c| c == '"' || c == '\'')
+            .to_string();
+        if path.is_empty() {
+            self.image_colors = Err("Enter the path of an image file".to_string());
+            self.image_extracted = None;
+            return;
+        }
+
+        // Nothing to do unless the image or the number of colors changed. The modification
+        // time catches a file rewritten under the same path.
+        let modified = std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        let key = (path, modified);
+        let extracted = Some((key.clone(), self.image_color_count));
+        if self.image_extracted == extracted {
+            return;
+        }
+        self.image_extracted = extracted;
+
+        if self.image_pixels.as_ref().map(|(cached, _)| cached) != Some(&key) {
+            match read_image_pixels(&key.0) {
+                Ok(pixels) => self.image_pixels = Some((key, pixels)),
+                Err(err) => {
+                    self.image_pixels = None;
+                    self.image_colors = Err(err);
+                    return;
+                }
+            }
+        }
+
+        self.image_colors = match &self.image_pixels {
+            Some((_, pixels)) if !pixels.is_empty() => {
+                Ok(median_cut(pixels, self.image_color_count))
+            }
+            _ => Err("The image has no opaque pixels".to_string()),
+        };
+    }
+
+    /// Increases the number of colors extracted from an image, up to 16.
+    pub fn increase_image_color_count(&mut self) {
+        self.image_color_count = (self.image_color_count + 1).min(16);
+    }
+
+    /// Decreases the number of colors extracted from an image, down to 1.
+    pub fn decrease_image_color_count(&mut self) {
+        self.image_color_count = (self.image_color_count - 1).max(1);
+    }
+
+    /// Exports the dominant colors of the image to a file, in all supported formats.
+    pub fn export_image_colors(&self) -> std::io::Result<()> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new("export/image_colors.txt");
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // Write the image path and each dominant color to the file.
+        writeln!(file, "Image: {}", self.first_line().trim())?;
+        match &self.image_colors {
+            Ok(colors) => {
+                for (index, dominant) in colors.iter().enumerate() {
+                    writeln!(file)?;
+                    writeln!(
+                        file,
+                        "Color {} ({:.1}% of pixels)",
+                        index + 1,
+                        dominant.share * 100.0
+                    )?;
+                    for (label, value) in color_formats(&dominant.color) {
+                        writeln!(file, "{}: {}", label, value)?;
+                    }
+                }
+            }
+            Err(err) => writeln!(file, "{}", err)?,
         }
+
+        Ok(())
+    }
+
+    /// Cycles through the modes of the color tool.
+    pub fn cycle_mode(&mut self) {
+        self.mode = match self.mode {
+            ColorMode::Convert => ColorMode::Contrast,
+            ColorMode::Contrast => ColorMode::Palette,
+            ColorMode::Palette => ColorMode::ColorBlindness,
+            ColorMode::ColorBlindness => ColorMode::Mix,
+            ColorMode::Mix => ColorMode::Image,
+            ColorMode::Image => ColorMode::Convert,
+        };
+    }
+
+    /// Cycles the format bare values are read in.
+    pub fn cycle_format(&mut self) {
+        self.format = match self.format {
+            ColorFormat::Auto => ColorFormat::Rgb,
+            ColorFormat::Rgb => ColorFormat::Hsl,
+            ColorFormat::Hsl => ColorFormat::Cmyk,
+            ColorFormat::Cmyk => ColorFormat::Hex,
+            ColorFormat::Hex => ColorFormat::Auto,
+        };
     }
 
     /// Converts the `input` color to all supported formats.
     pub fn convert_all(&mut self) {
+        let parsed = parse_color(self.first_line(), self.format);
+        self.color = parsed.as_ref().ok().copied();
+        self.ambiguous_hsl = ambiguous_hsl_reading(self.first_line(), self.format);
         self.convert_to_cmyk();
         self.convert_to_rgb();
         self.convert_to_hex();
         self.convert_to_hsl();
+        self.convert_to_color_spaces();
+        self.named_color = match parsed {
+            Ok(color) => nearest_named_color(&color),
+            Err(err) => err,
+        };
+        self.check_contrast();
+        self.palette = self.color.as_ref().map(generate_palette);
+        self.mix_colors();
+        if self.mode == ColorMode::Image {
+            self.extract_image_colors();
+        }
+    }
+
+    /// Cycles the format the palette is exported in.
+    pub fn cycle_palette_format(&mut self) {
+        self.palette_format = match self.palette_format {
+            PaletteFormat::Css => PaletteFormat::Scss,
+            PaletteFormat::Scss => PaletteFormat::Tailwind,
+            PaletteFormat::Tailwind => PaletteFormat::Json,
+            PaletteFormat::Json => PaletteFormat::Css,
+        };
+    }
+
+    /// Formats the palette in the selected export format.
+    pub fn palette_export(&self) -> String {
+        let palette = match &self.palette {
+            Some(palette) => palette,
+            None => return String::new(),
+        };
+
+        // Name every color once, e.g. `500` or `triadic-2`, so all formats list the same colors.
+        let shades: Vec<(String, String)> = palette
+            .shades
+            .iter()
+            .map(|(shade, color)| (shade.to_string(), convert_to_hex(color)))
+            .collect();
+        let schemes: Vec<(String, String)> = palette
+            .schemes
+            .iter()
+            .flat_map(|(scheme, colors)| {
+                let name = scheme.to_ascii_lowercase().replace(' ', "-");
+                colors
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, color)| (format!("{}-{}", name, i + 1), convert_to_hex(color)))
+            })
+            .collect();
+
+        let mut export = String::new();
+        match self.palette_format {
+            PaletteFormat::Css => {
+                export.push_str(":root {\n");
+                for (name, hex) in &shades {
+                    export.push_str(&format!("  --color-{}: {};\n", name, hex));
+                }
+                for (name, hex) in &schemes {
+                    export.push_str(&format!("  --{}: {};\n", name, hex));
+                }
+                export.push_str("}\n");
+            }
+            PaletteFormat::Scss => {
+                for (name, hex) in &shades {
+                    export.push_str(&format!("$color-{}: {};\n", name, hex));
+                }
+                for (name, hex) in &schemes {
+                    export.push_str(&format!("${}: {};\n", name, hex));
+                }
+            }
+            PaletteFormat::Tailwind => {
+                export.push_str("module.exports = {\n  theme: {\n    extend: {\n      colors: {\n");
+                export.push_str("        primary: {\n");
+                for (name, hex) in &shades {
+                    export.push_str(&format!("          {}: '{}',\n", name, hex));
+                }
+                export.push_str("        },\n");
+                for (name, hex) in &schemes {
+                    export.push_str(&format!("        '{}': '{}',\n", name, hex));
+                }
+                export.push_str("      },\n    },\n  },\n};\n");
+            }
+            PaletteFormat::Json => {
+                let entries = |colors: &[(String, String)]| {
+                    colors
+                        .iter()
+                        .map(|(name, hex)| format!("    \"{}\": \"{}\"", name, hex))
+                        .collect::<Vec<String>>()
+                        .join(",\n")
+                };
+                export.push_str(&format!(
+                    "{{\n  \"shades\": {{\n{}\n  }},\n  \"schemes\": {{\n{}\n  }}\n}}\n",
+                    entries(&shades),
+                    entries(&schemes)
+                ));
+            }
+        }
+        export
+    }
+
+    /// Exports the palette to a file in the selected format, returning the file path.
+    pub fn export_palette(&self) -> std::io::Result<&'static str> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new(self.palette_format.path());
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // Write the palette in the selected format to the file.
+        write!(file, "{}", self.palette_export())?;
+
+        Ok(self.palette_format.path())
     }
 
     /// Exports the converted color codes to a file.
@@ -92,19 +781,461 @@ impl ColorConverter {
         writeln!(file, "RGB: {}", self.rgb)?;
         writeln!(file, "HEX: {}", self.hex)?;
         writeln!(file, "HSL: {}", self.hsl)?;
+        writeln!(file, "HSV: {}", self.hsv)?;
+        writeln!(file, "HWB: {}", self.hwb)?;
+        writeln!(file, "XYZ: {}", self.xyz)?;
+        writeln!(file, "Lab: {}", self.lab)?;
+        writeln!(file, "LCH: {}", self.lch)?;
+        writeln!(file, "OKLab: {}", self.oklab)?;
+        writeln!(file, "OKLCH: {}", self.oklch)?;
+        writeln!(file, "Gamut: {}", self.gamut)?;
+        writeln!(file, "Named Color: {}", self.named_color)?;
+        if let Some(color) = &self.color {
+            writeln!(file)?;
+            writeln!(file, "Color Blindness Simulation")?;
+            for deficiency in Deficiency::ALL {
+                writeln!(
+                    file,
+                    "{}: {}",
+                    deficiency.label(),
+                    convert_to_hex(&deficiency.simulate(color))
+                )?;
+            }
+        }
+        if let Ok(check) = &self.contrast {
+            writeln!(file)?;
+            writeln!(file, "Contrast Check")?;
+            writeln!(
+                file,
+                "Text Color: {} (luminance {:.3})",
+                convert_to_hex(&check.foreground),
+                check.foreground_luminance
+            )?;
+            writeln!(
+                file,
+                "Background Color: {} (luminance {:.3})",
+                convert_to_hex(&check.background),
+                check.background_luminance
+            )?;
+            writeln!(file, "Contrast Ratio: {:.2}:1", check.ratio)?;
+            for (level, required) in WCAG_LEVELS {
+                let result = if check.ratio >= required {
+                    "Pass"
+                } else {
+                    "Fail"
+                };
+                writeln!(file, "{} ({}:1): {}", level, required, result)?;
+            }
+            if let Some((suggestion, ratio)) = &check.suggestion {
+                writeln!(
+                    file,
+                    "Nearest Text Color Passing AA: {} ({:.2}:1)",
+                    convert_to_hex(suggestion),
+                    ratio
+                )?;
+            }
+            for deficiency in Deficiency::ALL {
+                writeln!(
+                    file,
+                    "Contrast Ratio with {}: {:.2}:1",
+                    deficiency.label(),
+                    check.simulated_ratio(deficiency)
+                )?;
+            }
+        }
+        if let Some(hsl) = &self.ambiguous_hsl {
+            writeln!(
+                file,
+                "Ambiguous Input: read as RGB, as HSL it would be {}",
+                convert_to_hex(hsl)
+            )?;
+        }
 
         Ok(())
     }
 }
 
-/// Struct representing a color in RGB format.
-struct Color {
+/// Struct representing a color in RGB format, with an alpha channel.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Color {
     /// Red component of the color (0-255).
-    r: u8,
+    pub r: u8,
     /// Green component of the color (0-255).
-    g: u8,
+    pub g: u8,
     /// Blue component of the color (0-255).
-    b: u8,
+    pub b: u8,
+    /// Alpha component of the color (0.0 transparent - 1.0 opaque).
+    pub a: f32,
+    /// Whether the color was outside the sRGB gamut and was clipped to fit it.
+    pub clipped: bool,
+}
+
+impl Color {
+    /// Returns whether the color is fully opaque.
+    fn is_opaque(&self) -> bool {
+        self.a >= 1.0
+    }
+}
+
+/// The Tailwind-style shade scale: how far each shade is mixed with white (positive) or
+/// black (negative), with the base color at 500.
+const SHADE_SCALE: [(u16, f32); 10] = [
+    (50, 0.9),
+    (100, 0.8),
+    (200, 0.6),
+    (300, 0.4),
+    (400, 0.2),
+    (500, 0.0),
+    (600, -0.2),
+    (700, -0.4),
+    (800, -0.6),
+    (900, -0.8),
+];
+
+/// Generates shades and color schemes from a base color.
+fn generate_palette(base: &Color) -> Palette {
+    let white = color_from_rgb(0xFFFFFF);
+    let black = color_from_rgb(0x000000);
+    let shades = SHADE_SCALE
+        .iter()
+        .map(|(shade, amount)| {
+            let color = if *amount >= 0.0 {
+                mix_srgb(base, &white, *amount)
+            } else {
+                mix_srgb(base, &black, -amount)
+            };
+            (*shade, color)
+        })
+        .collect();
+
+    // Each scheme rotates the hue of the base color around the color wheel.
+    let scheme = |rotations: &[f32]| -> Vec<Color> {
+        std::iter::once(*base)
+            .chain(rotations.iter().map(|degrees| rotate_hue(base, *degrees)))
+            .collect()
+    };
+    let schemes = vec![
+        ("Complementary", scheme(&[180.0])),
+        ("Triadic", scheme(&[120.0, 240.0])),
+        ("Analogous", scheme(&[-30.0, 30.0])),
+        ("Split Complementary", scheme(&[150.0, 210.0])),
+    ];
+
+    Palette { shades, schemes }
+}
+
+/// Mixes two colors in sRGB, `amount` being the share of the second color from 0 to 1.
+fn mix_srgb(from: &Color, to: &Color, amount: f32) -> Color {
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * amount).round() as u8;
+    Color {
+        r: mix(from.r, to.r),
+        g: mix(from.g, to.g),
+        b: mix(from.b, to.b),
+        a: from.a + (to.a - from.a) * amount,
+        clipped: false,
+    }
+}
+
+/// The largest number of pixels sampled from an image, larger images are scaled down first.
+const MAX_SAMPLED_PIXELS: u32 = 256 * 256;
+
+/// Reads the opaque pixels of an image file, scaled down to at most `MAX_SAMPLED_PIXELS`.
+fn read_image_pixels(path: &str) -> Result<Vec<[u8; 3]>, String> {
+    let mut image =
+        image::open(path).map_err(|err| format!("Could not read `{}`: {}", path, err))?;
+    if image.width() * image.height() > MAX_SAMPLED_PIXELS {
+        // Nearest neighbor sampling keeps the original colors, instead of blending them.
+        image = image.resize(256, 256, FilterType::Nearest);
+    }
+
+    Ok(image
+        .to_rgba8()
+        .pixels()
+        .filter(|pixel| pixel[3] >= 128)
+        .map(|pixel| [pixel[0], pixel[1], pixel[2]])
+        .collect())
+}
+
+/// Finds up to `count` dominant colors of the pixels with the median cut algorithm: the box
+/// of pixels with the widest channel range is split at its median until there are `count`
+/// boxes, then each box stands for the average of its pixels.
+fn median_cut(pixels: &[[u8; 3]], count: usize) -> Vec<DominantColor> {
+    let mut boxes = vec![pixels.to_vec()];
+    while boxes.len() < count {
+        // Weigh the range by the pixel count, so large boxes are split before small outliers.
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .map(|(index, pixels)| {
+                let (channel, range) = (0..3)
+                    .map(|channel| {
+                        let values = pixels.iter().map(|pixel| pixel[channel]);
+                        let range = values.clone().max().unwrap_or(0) - values.min().unwrap_or(0);
+                        (channel, range)
+                    })
+                    .max_by_key(|&(_, range)| range)
+                    .unwrap_or((0, 0));
+                (index, channel, range as usize * pixels.len())
+            })
+            .filter(|&(_, _, score)| score > 0)
+            .max_by_key(|&(_, _, score)| score);
+
+        // Every box holds a single color, there is nothing left to split.
+        let Some((index, channel, _)) = widest else {
+            break;
+        };
+        let mut lower = boxes.swap_remove(index);
+        lower.sort_unstable_by_key(|pixel| pixel[channel]);
+        // Keep the pixels with the median value together, on the side that leaves both non-empty.
+        let median = lower[lower.len() / 2][channel];
+        let split = match lower.partition_point(|pixel| pixel[channel] < median) {
+            0 => lower.partition_point(|pixel| pixel[channel] <= median),
+            split => split,
+        };
+        let upper = lower.split_off(split);
+        boxes.push(lower);
+        boxes.push(upper);
+    }
+
+    let total = pixels.len() as f32;
+    let mut colors: Vec<DominantColor> = boxes
+        .iter()
+        .map(|pixels| {
+            let average = |channel: usize| {
+                let sum: u64 = pixels.iter().map(|pixel| pixel[channel] as u64).sum();
+                (sum as f64 / pixels.len() as f64).round() as u8
+            };
+            DominantColor {
+                color: Color {
+                    r: average(0),
+                    g: average(1),
+                    b: average(2),
+                    a: 1.0,
+                    clipped: false,
+                },
+                share: pixels.len() as f32 / total,
+            }
+        })
+        .collect();
+    colors.sort_by(|a, b| b.share.total_cmp(&a.share));
+    colors
+}
+
+/// Interpolates between two colors in a color space, `amount` being the share of the second
+/// color from 0 to 1.
+fn interpolate(from: &Color, to: &Color, amount: f32, space: MixSpace) -> Color {
+    let lerp = |a: f32, b: f32| a + (b - a) * amount;
+    let alpha = lerp(from.a, to.a);
+    let lerp_all = |a: [f32; 3], b: [f32; 3]| [0, 1, 2].map(|i| lerp(a[i], b[i]));
+
+    match space {
+        MixSpace::Srgb => mix_srgb(from, to, amount),
+        MixSpace::LinearRgb => linear_to_color(lerp_all(linear_rgb(from), linear_rgb(to)), alpha),
+        MixSpace::Hsl => {
+            let (h1, s1, l1) = to_hsl(from);
+            let (h2, s2, l2) = to_hsl(to);
+            // A gray has no hue, so it takes the hue of the other color.
+            let (h1, h2) = match (s1 == 0.0, s2 == 0.0) {
+                (true, false) => (h2, h2),
+                (false, true) => (h1, h1),
+                _ => (h1, h2),
+            };
+            // Go the shorter way around the color wheel.
+            let difference = (h2 - h1 + 540.0) % 360.0 - 180.0;
+            let hue = (h1 + difference * amount).rem_euclid(360.0);
+            hsl_to_color(hue, lerp(s1, s2), lerp(l1, l2), alpha)
+        }
+        MixSpace::Oklab => {
+            let lab = lerp_all(
+                linear_to_oklab(linear_rgb(from)),
+                linear_to_oklab(linear_rgb(to)),
+            );
+            linear_to_color(oklab_to_linear(lab), alpha)
+        }
+    }
+}
+
+/// Rotates the HSL hue of a color by a number of degrees.
+fn rotate_hue(color: &Color, degrees: f32) -> Color {
+    let (h, s, l) = to_hsl(color);
+    hsl_to_color((h + degrees).rem_euclid(360.0), s, l, color.a)
+}
+
+/// Returns black or white, whichever contrasts more with a color, to write text over it.
+pub fn readable_text_color(color: &Color) -> Color {
+    let luminance = relative_luminance(color);
+    if contrast_ratio(luminance, 0.0) >= contrast_ratio(luminance, 1.0) {
+        color_from_rgb(0x000000)
+    } else {
+        color_from_rgb(0xFFFFFF)
+    }
+}
+
+/// The WCAG 2 success criteria for text contrast, with their minimum contrast ratios.
+pub const WCAG_LEVELS: [(&str, f32); 4] = [
+    ("AA Normal Text", 4.5),
+    ("AA Large Text", 3.0),
+    ("AAA Normal Text", 7.0),
+    ("AAA Large Text", 4.5),
+];
+
+/// The WCAG contrast between a text color and a background color.
+pub struct ContrastCheck {
+    /// The text color, blended over the background when it is transparent.
+    pub foreground: Color,
+    /// The background color, blended over white when it is transparent.
+    pub background: Color,
+    /// The relative luminance of the text color, from 0 (black) to 1 (white).
+    pub foreground_luminance: f32,
+    /// The relative luminance of the background color.
+    pub background_luminance: f32,
+    /// The contrast ratio, from 1 to 21.
+    pub ratio: f32,
+    /// The nearest text color passing AA for normal text, changing only its HSL lightness, and
+    /// its contrast ratio. `None` when the text color already passes, or no lightness does.
+    pub suggestion: Option<(Color, f32)>,
+}
+
+impl ContrastCheck {
+    /// Calculates the contrast ratio as seen with a color vision deficiency.
+    pub fn simulated_ratio(&self, deficiency: Deficiency) -> f32 {
+        contrast_ratio(
+            relative_luminance(&deficiency.simulate(&self.foreground)),
+            relative_luminance(&deficiency.simulate(&self.background)),
+        )
+    }
+
+    /// Checks the contrast of a text color over a background color.
+    pub fn new(foreground: Color, background: Color) -> Self {
+        let white = color_from_rgb(0xFFFFFF);
+        let background = blend_over(&background, &white);
+        let foreground = blend_over(&foreground, &background);
+        let foreground_luminance = relative_luminance(&foreground);
+        let background_luminance = relative_luminance(&background);
+        let ratio = contrast_ratio(foreground_luminance, background_luminance);
+
+        let suggestion = if ratio >= 4.5 {
+            None
+        } else {
+            suggest_passing_color(&foreground, background_luminance, 4.5)
+        };
+
+        ContrastCheck {
+            foreground,
+            background,
+            foreground_luminance,
+            background_luminance,
+            ratio,
+            suggestion,
+        }
+    }
+}
+
+/// Calculates the WCAG relative luminance of a color, from 0 (black) to 1 (white).
+fn relative_luminance(color: &Color) -> f32 {
+    let [r, g, b] = linear_rgb(color);
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Calculates the WCAG contrast ratio between two relative luminances, from 1 to 21.
+fn contrast_ratio(first: f32, second: f32) -> f32 {
+    (first.max(second) + 0.05) / (first.min(second) + 0.05)
+}
+
+/// Blends a possibly transparent color over an opaque background.
+fn blend_over(color: &Color, background: &Color) -> Color {
+    let blend = |channel: u8, under: u8| {
+        (channel as f32 * color.a + under as f32 * (1.0 - color.a)).round() as u8
+    };
+    Color {
+        r: blend(color.r, background.r),
+        g: blend(color.g, background.g),
+        b: blend(color.b, background.b),
+        a: 1.0,
+        clipped: color.clipped,
+    }
+}
+
+/// Finds the color with the same HSL hue and saturation as `color` and the nearest lightness
+/// reaching `required` contrast against a background luminance, with its contrast ratio.
+fn suggest_passing_color(
+    color: &Color,
+    background_luminance: f32,
+    required: f32,
+) -> Option<(Color, f32)> {
+    let (h, s, l) = to_hsl(color);
+
+    // Step away from the current lightness, darker and lighter, in steps of 0.1%.
+    (1..=1000)
+        .flat_map(|step| [l - step as f32 * 0.001, l + step as f32 * 0.001])
+        .filter(|lightness| (0.0..=1.0).contains(lightness))
+        .map(|lightness| hsl_to_color(h, s, lightness, 1.0))
+        .map(|candidate| {
+            let ratio = contrast_ratio(relative_luminance(&candidate), background_luminance);
+            (candidate, ratio)
+        })
+        .find(|(_, ratio)| *ratio >= required)
+}
+
+/// Finds the ANSI 256-color palette index closest to an RGB color, for terminals without
+/// truecolor support. Only the 6x6x6 color cube (16-231) and the gray ramp (232-255) are
+/// considered, as the first 16 colors depend on the terminal theme.
+pub fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let distance = |(r2, g2, b2): (u8, u8, u8)| {
+        let dr = r as i32 - r2 as i32;
+        let dg = g as i32 - g2 as i32;
+        let db = b as i32 - b2 as i32;
+        dr * dr + dg * dg + db * db
+    };
+
+    // The nearest color in the cube, channel by channel.
+    let level = |channel: u8| {
+        (0..6)
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - channel as i32).abs())
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (16 + 36 * ri + 6 * gi + bi) as u8;
+    let cube_rgb = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+
+    // The nearest gray in the ramp, which runs from 8 to 238 in steps of 10.
+    let average = (r as i32 + g as i32 + b as i32) / 3;
+    let step = ((average - 8 + 5) / 10).clamp(0, 23);
+    let gray = (8 + 10 * step) as u8;
+
+    if distance((gray, gray, gray)) < distance(cube_rgb) {
+        232 + step as u8
+    } else {
+        cube
+    }
+}
+
+/// Converts a color to all supported formats, each after its name, e.g. `("HEX", "#FCBA03")`.
+pub fn color_formats(color: &Color) -> Vec<(&'static str, String)> {
+    vec![
+        ("CMYK", convert_to_cmyk(color)),
+        ("RGB", convert_to_rgb(color)),
+        ("HEX", convert_to_hex(color)),
+        ("HSL", convert_to_hsl(color)),
+        ("HSV", convert_to_hsv(color)),
+        ("HWB", convert_to_hwb(color)),
+        ("XYZ", convert_to_xyz(color)),
+        ("Lab", convert_to_lab(color)),
+        ("LCH", convert_to_lch(color)),
+        ("OKLab", convert_to_oklab(color)),
+        ("OKLCH", convert_to_oklch(color)),
+        ("Named Color", nearest_named_color(color)),
+    ]
+}
+
+/// Formats an alpha value with up to three decimals, e.g. `0.5`.
+fn format_alpha(alpha: f32) -> String {
+    let formatted = format!("{:.3}", alpha);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
 }
 
 /// Converts a color from RGB to CMYK format.
@@ -113,53 +1244,202 @@ fn convert_to_cmyk(color: &Color) -> String {
     let g = color.g as f32 / 255.0;
     let b = color.b as f32 / 255.0;
 
-    // Calculate CMYK values.
+    // Calculate CMYK values, black being all key and no color.
     let k = 1.0 - r.max(g).max(b);
-    let c = (1.0 - r - k) / (1.0 - k);
-    let m = (1.0 - g - k) / (1.0 - k);
-    let y = (1.0 - b - k) / (1.0 - k);
+    let (c, m, y) = if 1.0 - k == 0.0 {
+        (0.0, 0.0, 0.0)
+    } else {
+        (
+            (1.0 - r - k) / (1.0 - k),
+            (1.0 - g - k) / (1.0 - k),
+            (1.0 - b - k) / (1.0 - k),
+        )
+    };
 
     // Format the CMYK values as a string.
-    format!(
+    let cmyk = format!(
         "{:.0}%, {:.0}%, {:.0}%, {:.0}%",
         c * 100.0,
         m * 100.0,
         y * 100.0,
         k * 100.0
-    )
+    );
+
+    // CMYK has no alpha channel, so transparency is noted separately.
+    if color.is_opaque() {
+        cmyk
+    } else {
+        format!(
+            "{}\nAlpha: {:.0}% (not part of CMYK)",
+            cmyk,
+            color.a * 100.0
+        )
+    }
 }
 
 /// Converts a color from RGB to RGB format.
 fn convert_to_rgb(color: &Color) -> String {
-    format!("{}, {}, {}", color.r, color.g, color.b)
+    if color.is_opaque() {
+        format!("{}, {}, {}", color.r, color.g, color.b)
+    } else {
+        format!(
+            "rgba({}, {}, {}, {})",
+            color.r,
+            color.g,
+            color.b,
+            format_alpha(color.a)
+        )
+    }
 }
 
 /// Converts a color from RGB to HEX format.
-fn convert_to_hex(color: &Color) -> String {
-    format!("#{:02X}{:02X}{:02X}", color.r, color.g, color.b)
+pub fn convert_to_hex(color: &Color) -> String {
+    if color.is_opaque() {
+        format!("#{:02X}{:02X}{:02X}", color.r, color.g, color.b)
+    } else {
+        format!(
+            "#{:02X}{:02X}{:02X}{:02X}",
+            color.r,
+            color.g,
+            color.b,
+            (color.a * 255.0).round() as u8
+        )
+    }
 }
 
 /// Converts a color from RGB to HSL format.
 fn convert_to_hsl(color: &Color) -> String {
+    let (h, s, l) = to_hsl(color);
+
+    // Format the HSL values as a string.
+    if color.is_opaque() {
+        format!("{:.0}°, {:.0}%, {:.0}%", h, s * 100.0, l * 100.0)
+    } else {
+        format!(
+            "hsla({:.0}, {:.0}%, {:.0}%, {})",
+            h,
+            s * 100.0,
+            l * 100.0,
+            format_alpha(color.a)
+        )
+    }
+}
+
+/// Converts a color from RGB to HSV (HSB) format.
+fn convert_to_hsv(color: &Color) -> String {
     let r = color.r as f32 / 255.0;
     let g = color.g as f32 / 255.0;
     let b = color.b as f32 / 255.0;
 
-    // Calculate HSL values.
+    // Calculate HSV values.
     let max = r.max(g).max(b);
     let min = r.min(g).min(b);
-    let diff = max - min;
+    let h = hue(r, g, b);
+    let s = if max == 0.0 { 0.0 } else { (max - min) / max };
 
-    // Calculate hue.
-    let h = if max == min {
-        0.0
-    } else if max == r {
-        (60.0 * ((g - b) / diff) + 360.0) % 360.0
-    } else if max == g {
-        60.0 * ((b - r) / diff) + 120.0
+    // Format the HSV values as a string.
+    if color.is_opaque() {
+        format!("{:.0}°, {:.0}%, {:.0}%", h, s * 100.0, max * 100.0)
     } else {
-        60.0 * ((r - g) / diff) + 240.0
-    };
+        format!(
+            "hsva({:.0}, {:.0}%, {:.0}%, {})",
+            h,
+            s * 100.0,
+            max * 100.0,
+            format_alpha(color.a)
+        )
+    }
+}
+
+/// Converts a color from RGB to HWB format.
+fn convert_to_hwb(color: &Color) -> String {
+    let r = color.r as f32 / 255.0;
+    let g = color.g as f32 / 255.0;
+    let b = color.b as f32 / 255.0;
+
+    // Whiteness is the smallest channel, and blackness is what the largest channel lacks.
+    let whiteness = r.min(g).min(b);
+    let blackness = 1.0 - r.max(g).max(b);
+
+    format!(
+        "hwb({:.0} {:.0}% {:.0}%{})",
+        hue(r, g, b),
+        whiteness * 100.0,
+        blackness * 100.0,
+        css_alpha(color)
+    )
+}
+
+/// Converts a color from RGB to CIE XYZ, relative to the D65 white point.
+fn convert_to_xyz(color: &Color) -> String {
+    let [x, y, z] = multiply(&SRGB_TO_XYZ_D65, linear_rgb(color));
+    format!(
+        "color(xyz-d65 {} {} {}{})",
+        fixed(x, 4),
+        fixed(y, 4),
+        fixed(z, 4),
+        css_alpha(color)
+    )
+}
+
+/// Converts a color from RGB to CIE Lab, relative to the D50 white point as in CSS.
+fn convert_to_lab(color: &Color) -> String {
+    let [l, a, b] = color_to_lab(color);
+    format!(
+        "lab({} {} {}{})",
+        fixed(l, 2),
+        fixed(a, 2),
+        fixed(b, 2),
+        css_alpha(color)
+    )
+}
+
+/// Converts a color from RGB to CIE LCH, the polar form of CIE Lab.
+fn convert_to_lch(color: &Color) -> String {
+    let [l, c, h] = to_polar(color_to_lab(color), 2);
+    format!(
+        "lch({} {} {}{})",
+        fixed(l, 2),
+        fixed(c, 2),
+        fixed(h, 2),
+        css_alpha(color)
+    )
+}
+
+/// Converts a color from RGB to OKLab.
+fn convert_to_oklab(color: &Color) -> String {
+    let [l, a, b] = linear_to_oklab(linear_rgb(color));
+    format!(
+        "oklab({} {} {}{})",
+        fixed(l, 4),
+        fixed(a, 4),
+        fixed(b, 4),
+        css_alpha(color)
+    )
+}
+
+/// Converts a color from RGB to OKLCH, the polar form of OKLab.
+fn convert_to_oklch(color: &Color) -> String {
+    let [l, c, h] = to_polar(linear_to_oklab(linear_rgb(color)), 4);
+    format!(
+        "oklch({} {} {}{})",
+        fixed(l, 4),
+        fixed(c, 4),
+        fixed(h, 2),
+        css_alpha(color)
+    )
+}
+
+/// Calculates the hue in degrees, and the saturation and lightness from 0 to 1, of a color.
+fn to_hsl(color: &Color) -> (f32, f32, f32) {
+    let r = color.r as f32 / 255.0;
+    let g = color.g as f32 / 255.0;
+    let b = color.b as f32 / 255.0;
+
+    // Calculate HSL values.
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let diff = max - min;
 
     // Calculate lightness.
     let l = (max + min) / 2.0;
@@ -173,87 +1453,548 @@ fn convert_to_hsl(color: &Color) -> String {
         diff / (2.0 - max - min)
     };
 
-    // Format the HSL values as a string.
-    format!("{:.0}°, {:.0}%, {:.0}%", h, s * 100.0, l * 100.0)
+    (hue(r, g, b), s, l)
+}
+
+/// Calculates the hue in degrees of RGB values from 0 to 1.
+fn hue(r: f32, g: f32, b: f32) -> f32 {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let diff = max - min;
+
+    if max == min {
+        0.0
+    } else if max == r {
+        (60.0 * ((g - b) / diff) + 360.0) % 360.0
+    } else if max == g {
+        60.0 * ((b - r) / diff) + 120.0
+    } else {
+        60.0 * ((r - g) / diff) + 240.0
+    }
 }
 
-/// Attempts to parse a color from various supported formats.
-fn parse_color(input: &str) -> Option<Color> {
-    parse_hex(input)
-        .or_else(|| parse_rgb(input))
-        .or_else(|| parse_cmyk(input))
-        .or_else(|| parse_hsl(input))
+/// Formats the alpha of a color as the ` / alpha` suffix of the CSS functional notations,
+/// or nothing when the color is opaque.
+fn css_alpha(color: &Color) -> String {
+    if color.is_opaque() {
+        String::new()
+    } else {
+        format!(" / {}", format_alpha(color.a))
+    }
 }
 
-/// Parses a color from HEX format.
-fn parse_hex(input: &str) -> Option<Color> {
-    // Remove the '#' prefix if present.
-    let input = input.trim_start_matches('#');
-    if input.len() != 6 {
+/// Formats a value with a fixed number of decimals, without printing `-0`.
+fn fixed(value: f32, decimals: usize) -> String {
+    let formatted = format!("{:.*}", decimals, value);
+    match formatted.strip_prefix('-') {
+        Some(positive) if positive.chars().all(|c| c == '0' || c == '.') => positive.to_string(),
+        _ => formatted,
+    }
+}
+
+/// Attempts to parse a color from various supported formats, reading bare values in the
+/// given format. Returns a message explaining why the input is invalid otherwise.
+fn parse_color(input: &str, format: ColorFormat) -> Result<Color, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Invalid color format".to_string());
+    }
+
+    // An explicit prefix, e.g. `hsl: 44, 98, 50`, overrides the selected format.
+    let (format, input) = split_prefix(input).unwrap_or((format, input));
+
+    if let Some(color) = parse_named(input) {
+        return Ok(color);
+    }
+    if input.contains('(') {
+        return parse_function(input);
+    }
+
+    match format {
+        ColorFormat::Auto => parse_auto(input),
+        ColorFormat::Cmyk => parse_cmyk(input),
+        ColorFormat::Hex => parse_hex(input, true),
+        ColorFormat::Hsl => parse_hsl(input),
+        ColorFormat::Rgb => parse_rgb(input),
+    }
+}
+
+/// Splits an explicit format prefix, e.g. `rgb:`, `hsl:`, `cmyk:` or `hex:`, from the input.
+fn split_prefix(input: &str) -> Option<(ColorFormat, &str)> {
+    let (prefix, rest) = input.split_once(':')?;
+    let format = match prefix.trim().to_ascii_lowercase().as_str() {
+        "cmyk" => ColorFormat::Cmyk,
+        "hex" => ColorFormat::Hex,
+        "hsl" => ColorFormat::Hsl,
+        "rgb" => ColorFormat::Rgb,
+        _ => return None,
+    };
+    Some((format, rest.trim()))
+}
+
+/// Detects the format of bare input: four values are CMYK, and three values are HSL when
+/// they carry units such as `°` or `%`, or RGB otherwise.
+fn parse_auto(input: &str) -> Result<Color, String> {
+    if !input.contains(',') {
+        let digits = input.strip_prefix('#').unwrap_or(input);
+        if !input.starts_with('#') && !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!(
+                "Invalid color format: `{}` is not a color name, HEX code or list of values",
+                input
+            ));
+        }
+        return parse_hex(input, false);
+    }
+
+    match input.split(',').count() {
+        3 if input.contains(['%', '°']) => parse_hsl(input),
+        // Bare values are RGB, HSL needs a `%` or `°` marker or the HSL input format.
+        3 => parse_rgb(input),
+        4 => parse_cmyk(input),
+        count => Err(format!(
+            "Expected 3 values (RGB or HSL) or 4 values (CMYK), found {}",
+            count
+        )),
+    }
+}
+
+/// Returns the HSL reading of bare input that is also valid RGB, e.g. `44, 98, 50`. Such
+/// input is read as RGB unless a prefix or an input format says otherwise.
+fn ambiguous_hsl_reading(input: &str, format: ColorFormat) -> Option<Color> {
+    let input = input.trim();
+    if format != ColorFormat::Auto || split_prefix(input).is_some() {
         return None;
     }
-    // Parse the HEX string to a u32.
-    u32::from_str_radix(input, 16).ok().map(|rgb| Color {
-        r: ((rgb >> 16) & 0xFF) as u8,
-        g: ((rgb >> 8) & 0xFF) as u8,
-        b: (rgb & 0xFF) as u8,
+
+    let values: Vec<&str> = input.split(',').map(str::trim).collect();
+    if values.len() != 3 || values.iter().any(|value| value.parse::<f32>().is_err()) {
+        return None;
+    }
+
+    parse_rgb(input).ok()?;
+    parse_hsl(input).ok()
+}
+
+/// Parses a color from HEX format: `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`.
+/// The short forms require the `#` prefix, unless `bare_short` is set.
+fn parse_hex(input: &str, bare_short: bool) -> Result<Color, String> {
+    // Remove the '#' prefix if present.
+    let digits = input.strip_prefix('#').unwrap_or(input);
+    if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid color format: `{}` is not a HEX digit", c));
+    }
+
+    // Expand the short forms by doubling each digit, e.g. `#fa0` to `#ffaa00`.
+    let digits = match digits.len() {
+        3 | 4 if bare_short || input.starts_with('#') => {
+            digits.chars().flat_map(|c| [c, c]).collect()
+        }
+        3 | 4 => {
+            return Err(format!(
+                "Short HEX codes need a `#` prefix, e.g. `#{}`",
+                digits
+            ))
+        }
+        6 | 8 => digits.to_string(),
+        count => {
+            return Err(format!(
+                "HEX codes have 3, 4, 6 or 8 digits, found {}",
+                count
+            ))
+        }
+    };
+
+    // Parse the HEX string to a u32, adding an opaque alpha when it is missing.
+    let rgba = u32::from_str_radix(&digits, 16).map_err(|err| err.to_string())?;
+    let rgba = if digits.len() == 6 {
+        (rgba << 8) | 0xFF
+    } else {
+        rgba
+    };
+    Ok(Color {
+        r: ((rgba >> 24) & 0xFF) as u8,
+        g: ((rgba >> 16) & 0xFF) as u8,
+        b: ((rgba >> 8) & 0xFF) as u8,
+        a: (rgba & 0xFF) as f32 / 255.0,
+        clipped: false,
     })
 }
 
-/// Parses a color from RGB format.
-fn parse_rgb(input: &str) -> Option<Color> {
-    // Split the RGB string by commas.
-    let parts: Vec<&str> = input.split(',').collect();
-    if parts.len() != 3 {
-        return None;
+/// Parses a CSS named color, e.g. `rebeccapurple`, or `transparent`.
+fn parse_named(input: &str) -> Option<Color> {
+    if input.eq_ignore_ascii_case("transparent") {
+        return Some(Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0.0,
+            clipped: false,
+        });
     }
-    // Parse the RGB values.
-    let r = parts[0].trim().parse().ok()?;
-    let g = parts[1].trim().parse().ok()?;
-    let b = parts[2].trim().parse().ok()?;
 
-    Some(Color { r, g, b })
+    NAMED_COLORS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(input))
+        .map(|(_, rgb)| color_from_rgb(*rgb))
 }
 
-/// Parses a color from CMYK format.
-fn parse_cmyk(input: &str) -> Option<Color> {
-    // Split the CMYK string by commas.
-    let parts: Vec<&str> = input.split(',').collect();
-    if parts.len() != 4 {
-        return None;
+/// Parses the CSS functional notations `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`, `lab()`,
+/// `lch()`, `oklab()`, `oklch()` and `color()`, with comma or space separated values, e.g.
+/// `rgb(255, 0, 0)`, `rgb(255 0 0 / 50%)` or `hsl(120deg 50% 50%)`, as well as `hsv()` and
+/// `cmyk()` with four values.
+fn parse_function(input: &str) -> Result<Color, String> {
+    let open = input.find('(').unwrap_or_default();
+    let name = input[..open].trim().to_ascii_lowercase();
+    let arguments = input[open + 1..]
+        .trim_end()
+        .strip_suffix(')')
+        .ok_or_else(|| format!("Missing `)` after {}(", name))?;
+
+    let (label, count) = match name.as_str() {
+        "rgb" | "rgba" => ("RGB", 3),
+        "hsl" | "hsla" => ("HSL", 3),
+        "hsv" | "hsva" | "hsb" | "hsba" => ("HSV", 3),
+        "hwb" => ("HWB", 3),
+        "lab" => ("Lab", 3),
+        "lch" => ("LCH", 3),
+        "oklab" => ("OKLab", 3),
+        "oklch" => ("OKLCH", 3),
+        "cmyk" => ("CMYK", 4),
+        // The first value of `color()` names the color space, e.g. `color(xyz 0.4 0.2 0.1)`.
+        "color" => ("color()", 4),
+        _ => return Err(format!("Unknown color function `{}()`", name)),
+    };
+    let (values, alpha) = split_arguments(arguments, label, count)?;
+    let a = match alpha {
+        Some(alpha) => parse_alpha(alpha)?,
+        None => 1.0,
+    };
+
+    match label {
+        "RGB" => rgb_from_values(&values, a),
+        "HSL" => hsl_from_values(&values, a),
+        "HSV" => hsv_from_values(&values, a),
+        "HWB" => hwb_from_values(&values, a),
+        "Lab" => lab_from_values(&values, a),
+        "LCH" => lch_from_values(&values, a),
+        "OKLab" => oklab_from_values(&values, a),
+        "OKLCH" => oklch_from_values(&values, a),
+        "CMYK" => cmyk_from_values(&values, a),
+        _ => color_function_from_values(&values, a),
+    }
+}
+
+/// Splits function arguments into values and an optional alpha, in either the legacy comma
+/// separated syntax or the modern space separated syntax with a `/` before the alpha.
+fn split_arguments<'a>(
+    arguments: &'a str,
+    label: &str,
+    count: usize,
+) -> Result<(Vec<&'a str>, Option<&'a str>), String> {
+    let (values, alpha) = if arguments.contains(',') {
+        let mut values: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        let alpha = if values.len() == count + 1 {
+            values.pop()
+        } else {
+            None
+        };
+        (values, alpha)
+    } else {
+        match arguments.split_once('/') {
+            Some((values, alpha)) => (values.split_whitespace().collect(), Some(alpha.trim())),
+            None => (arguments.split_whitespace().collect(), None),
+        }
+    };
+
+    if values.len() != count {
+        return Err(format!(
+            "{} needs {} values, found {}",
+            label,
+            count,
+            values.len()
+        ));
+    }
+    Ok((values, alpha))
+}
+
+/// Splits bare, comma separated values, checking that there are `count` of them.
+fn split_values<'a>(input: &'a str, label: &str, count: usize) -> Result<Vec<&'a str>, String> {
+    let values: Vec<&str> = input.split(',').map(str::trim).collect();
+    if values.len() != count {
+        return Err(format!(
+            "{} needs {} values, found {}",
+            label,
+            count,
+            values.len()
+        ));
     }
+    Ok(values)
+}
 
-    // Parse the CMYK values as percentages.
-    let c: f32 = parts[0].trim().trim_end_matches('%').parse().ok()?;
-    let m: f32 = parts[1].trim().trim_end_matches('%').parse().ok()?;
-    let y: f32 = parts[2].trim().trim_end_matches('%').parse().ok()?;
-    let k: f32 = parts[3].trim().trim_end_matches('%').parse().ok()?;
+/// Parses a number, naming the value it belongs to in the error.
+fn parse_number(value: &str, name: &str) -> Result<f32, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("{} `{}` is not a number", name, value))
+}
+
+/// Parses an RGB channel, either a number from 0 to 255 or a percentage.
+fn parse_rgb_channel(value: &str, name: &str) -> Result<u8, String> {
+    let channel = match value.strip_suffix('%') {
+        Some(percentage) => {
+            let percentage = parse_number(percentage, name)?;
+            if !(0.0..=100.0).contains(&percentage) {
+                return Err(format!("{} {}% is out of range (0-100%)", name, percentage));
+            }
+            percentage * 2.55
+        }
+        None => {
+            let channel = parse_number(value, name)?;
+            if !(0.0..=255.0).contains(&channel) {
+                return Err(format!("{} {} is out of range (0-255)", name, channel));
+            }
+            channel
+        }
+    };
+    Ok(channel.round() as u8)
+}
+
+/// Parses a hue in degrees, optionally with a `deg`, `grad`, `rad` or `turn` unit.
+fn parse_hue(value: &str) -> Result<f32, String> {
+    let lowercase = value.to_ascii_lowercase();
+    let (number, degrees_per_unit) = if let Some(number) = lowercase.strip_suffix("deg") {
+        (number, 1.0)
+    } else if let Some(number) = lowercase.strip_suffix("grad") {
+        (number, 0.9)
+    } else if let Some(number) = lowercase.strip_suffix("rad") {
+        (number, 180.0 / std::f32::consts::PI)
+    } else if let Some(number) = lowercase.strip_suffix("turn") {
+        (number, 360.0)
+    } else {
+        (lowercase.trim_end_matches('°'), 1.0)
+    };
+    let hue = number
+        .trim()
+        .parse::<f32>()
+        .map_err(|_| format!("Hue `{}` is not a number or angle", value))?
+        * degrees_per_unit;
+    Ok(hue.rem_euclid(360.0))
+}
+
+/// Parses a value from 0 to 100, with or without a `%` sign, as a fraction.
+fn parse_percentage(value: &str, name: &str) -> Result<f32, String> {
+    let percentage = parse_number(value.trim_end_matches('%'), name)?;
+    if !(0.0..=100.0).contains(&percentage) {
+        return Err(format!("{} {}% is out of range (0-100%)", name, percentage));
+    }
+    Ok(percentage / 100.0)
+}
+
+/// Parses an alpha value, either a number from 0 to 1 or a percentage.
+fn parse_alpha(value: &str) -> Result<f32, String> {
+    match value.strip_suffix('%') {
+        Some(_) => parse_percentage(value, "Alpha"),
+        None => {
+            let alpha = parse_number(value, "Alpha")?;
+            if !(0.0..=1.0).contains(&alpha) {
+                return Err(format!("Alpha {} is out of range (0-1)", alpha));
+            }
+            Ok(alpha)
+        }
+    }
+}
+
+/// Parses a color from RGB format, `252, 186, 3`.
+fn parse_rgb(input: &str) -> Result<Color, String> {
+    rgb_from_values(&split_values(input, "RGB", 3)?, 1.0)
+}
+
+/// Parses a color from CMYK format, `0, 26, 99, 1` or `0%, 26%, 99%, 1%`.
+fn parse_cmyk(input: &str) -> Result<Color, String> {
+    cmyk_from_values(&split_values(input, "CMYK", 4)?, 1.0)
+}
+
+/// Parses a color from HSL format, `44, 98, 50` or `44°, 98%, 50%`.
+fn parse_hsl(input: &str) -> Result<Color, String> {
+    hsl_from_values(&split_values(input, "HSL", 3)?, 1.0)
+}
+
+/// Creates a color from red, green and blue values.
+fn rgb_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    Ok(Color {
+        r: parse_rgb_channel(values[0], "Red")?,
+        g: parse_rgb_channel(values[1], "Green")?,
+        b: parse_rgb_channel(values[2], "Blue")?,
+        a,
+        clipped: false,
+    })
+}
+
+/// Creates a color from cyan, magenta, yellow and key percentages.
+fn cmyk_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    let c = parse_percentage(values[0], "Cyan")?;
+    let m = parse_percentage(values[1], "Magenta")?;
+    let y = parse_percentage(values[2], "Yellow")?;
+    let k = parse_percentage(values[3], "Key (black)")?;
 
     // Convert CMYK to RGB.
-    let r = (255.0 * (1.0 - c / 100.0) * (1.0 - k / 100.0)) as u8;
-    let g = (255.0 * (1.0 - m / 100.0) * (1.0 - k / 100.0)) as u8;
-    let b = (255.0 * (1.0 - y / 100.0) * (1.0 - k / 100.0)) as u8;
+    let r = (255.0 * (1.0 - c) * (1.0 - k)) as u8;
+    let g = (255.0 * (1.0 - m) * (1.0 - k)) as u8;
+    let b = (255.0 * (1.0 - y) * (1.0 - k)) as u8;
 
-    Some(Color { r, g, b })
+    Ok(Color {
+        r,
+        g,
+        b,
+        a,
+        clipped: false,
+    })
 }
 
-/// Parses a color from HSL format.
-fn parse_hsl(input: &str) -> Option<Color> {
-    // Split the HSL string by commas.
-    let parts: Vec<&str> = input.split(',').collect();
-    if parts.len() != 3 {
-        return None;
+/// Creates a color from hue, saturation and lightness values.
+fn hsl_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    Ok(hsl_to_color(
+        parse_hue(values[0])?,
+        parse_percentage(values[1], "Saturation")?,
+        parse_percentage(values[2], "Lightness")?,
+        a,
+    ))
+}
+
+/// Creates a color from hue, saturation and value (brightness) values.
+fn hsv_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    Ok(hsv_to_color(
+        parse_hue(values[0])?,
+        parse_percentage(values[1], "Saturation")?,
+        parse_percentage(values[2], "Value")?,
+        a,
+    ))
+}
+
+/// Creates a color from hue, whiteness and blackness values.
+fn hwb_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    Ok(hwb_to_color(
+        parse_hue(values[0])?,
+        parse_percentage(values[1], "Whiteness")?,
+        parse_percentage(values[2], "Blackness")?,
+        a,
+    ))
+}
+
+/// Creates a color from CIE Lab lightness (0-100) and a and b axis values.
+fn lab_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    let lab = [
+        parse_bounded(values[0], "Lightness", 100.0, 0.0, 100.0)?,
+        parse_scaled(values[1], "a", 125.0)?,
+        parse_scaled(values[2], "b", 125.0)?,
+    ];
+    Ok(lab_to_color(lab, a))
+}
+
+/// Creates a color from CIE LCH lightness (0-100), chroma and hue values.
+fn lch_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    let lch = [
+        parse_bounded(values[0], "Lightness", 100.0, 0.0, 100.0)?,
+        parse_bounded(values[1], "Chroma", 150.0, 0.0, f32::INFINITY)?,
+        parse_hue(values[2])?,
+    ];
+    Ok(lab_to_color(from_polar(lch), a))
+}
+
+/// Creates a color from OKLab lightness (0-1) and a and b axis values.
+fn oklab_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    let lab = [
+        parse_bounded(values[0], "Lightness", 1.0, 0.0, 1.0)?,
+        parse_scaled(values[1], "a", 0.4)?,
+        parse_scaled(values[2], "b", 0.4)?,
+    ];
+    Ok(linear_to_color(oklab_to_linear(lab), a))
+}
+
+/// Creates a color from OKLCH lightness (0-1), chroma and hue values.
+fn oklch_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    let lch = [
+        parse_bounded(values[0], "Lightness", 1.0, 0.0, 1.0)?,
+        parse_bounded(values[1], "Chroma", 0.4, 0.0, f32::INFINITY)?,
+        parse_hue(values[2])?,
+    ];
+    Ok(linear_to_color(oklab_to_linear(from_polar(lch)), a))
+}
+
+/// Creates a color from the `color()` notation: a color space, `srgb`, `srgb-linear`, `xyz`,
+/// `xyz-d65` or `xyz-d50`, followed by three values.
+fn color_function_from_values(values: &[&str], a: f32) -> Result<Color, String> {
+    let space = values[0].to_ascii_lowercase();
+    let channels = [
+        parse_scaled(values[1], "Channel", 1.0)?,
+        parse_scaled(values[2], "Channel", 1.0)?,
+        parse_scaled(values[3], "Channel", 1.0)?,
+    ];
+
+    let linear = match space.as_str() {
+        "srgb" => channels.map(srgb_to_linear),
+        "srgb-linear" => channels,
+        "xyz" | "xyz-d65" => multiply(&XYZ_D65_TO_SRGB, channels),
+        "xyz-d50" => multiply(&XYZ_D65_TO_SRGB, multiply(&D50_TO_D65, channels)),
+        _ => return Err(format!("Unsupported color space `{}` in color()", space)),
+    };
+    Ok(linear_to_color(linear, a))
+}
+
+/// Parses a number, or a percentage of `full_scale`.
+fn parse_scaled(value: &str, name: &str, full_scale: f32) -> Result<f32, String> {
+    match value.strip_suffix('%') {
+        Some(percentage) => Ok(parse_number(percentage, name)? / 100.0 * full_scale),
+        None => parse_number(value, name),
     }
+}
 
-    // Parse the HSL values.
-    let h: f32 = parts[0].trim().trim_end_matches('°').parse().ok()?;
-    let s: f32 = parts[1].trim().trim_end_matches('%').parse::<f32>().ok()? / 100.0;
-    let l: f32 = parts[2].trim().trim_end_matches('%').parse::<f32>().ok()? / 100.0;
+/// Parses a number, or a percentage of `full_scale`, checking it is within `min` and `max`.
+fn parse_bounded(
+    value: &str,
+    name: &str,
+    full_scale: f32,
+    min: f32,
+    max: f32,
+) -> Result<f32, String> {
+    let number = parse_scaled(value, name, full_scale)?;
+    if number < min || number > max {
+        let range = if max.is_infinite() {
+            format!("{} or more", min)
+        } else {
+            format!("{}-{}", min, max)
+        };
+        return Err(format!("{} {} is out of range ({})", name, value, range));
+    }
+    Ok(number)
+}
 
-    // Convert HSL to RGB.
+/// Converts a hue in degrees, and saturation and lightness from 0 to 1, to a color.
+fn hsl_to_color(h: f32, s: f32, l: f32, a: f32) -> Color {
     let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    hue_to_color(h, c, l - c / 2.0, a)
+}
+
+/// Converts a hue in degrees, and saturation and value from 0 to 1, to a color.
+fn hsv_to_color(h: f32, s: f32, v: f32, a: f32) -> Color {
+    let c = v * s;
+    hue_to_color(h, c, v - c, a)
+}
+
+/// Converts a hue in degrees, and whiteness and blackness from 0 to 1, to a color.
+fn hwb_to_color(h: f32, w: f32, b: f32, a: f32) -> Color {
+    // Whiteness and blackness adding up to more than 100% give a shade of gray.
+    if w + b >= 1.0 {
+        let gray = w / (w + b);
+        return hsv_to_color(h, 0.0, gray, a);
+    }
+    hsv_to_color(h, 1.0 - w / (1.0 - b), 1.0 - b, a)
+}
+
+/// Converts a hue in degrees, a chroma and a lightness offset `m`, all from 0 to 1, to a color.
+fn hue_to_color(h: f32, c: f32, m: f32, a: f32) -> Color {
     let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
-    let m = l - c / 2.0;
 
     // Calculate RGB values based on hue.
     let (r, g, b) = if h < 60.0 {
@@ -270,9 +2011,403 @@ fn parse_hsl(input: &str) -> Option<Color> {
         (c, 0.0, x)
     };
 
-    Some(Color {
-        r: ((r + m) * 255.0) as u8,
-        g: ((g + m) * 255.0) as u8,
-        b: ((b + m) * 255.0) as u8,
-    })
+    Color {
+        r: ((r + m) * 255.0).round() as u8,
+        g: ((g + m) * 255.0).round() as u8,
+        b: ((b + m) * 255.0).round() as u8,
+        a,
+        clipped: false,
+    }
+}
+
+/// The matrix converting linear sRGB to CIE XYZ, relative to the D65 white point.
+const SRGB_TO_XYZ_D65: [[f32; 3]; 3] = [
+    [0.412_456_4, 0.357_576_1, 0.180_437_5],
+    [0.212_672_9, 0.715_152_2, 0.072_175],
+    [0.019_333_9, 0.119_192, 0.950_304_1],
+];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear sRGB.
+const XYZ_D65_TO_SRGB: [[f32; 3]; 3] = [
+    [3.240_454_2, -1.537_138_5, -0.498_531_4],
+    [-0.969_266, 1.876_010_8, 0.041_556],
+    [0.055_643_4, -0.204_025_9, 1.057_225_2],
+];
+
+/// The Bradford chromatic adaptation from the D65 white point to D50.
+const D65_TO_D50: [[f32; 3]; 3] = [
+    [1.047_929_8, 0.022_946_793, -0.050_192_23],
+    [0.029_627_815, 0.990_434_5, -0.017_073_825],
+    [-0.009_243_058, 0.015_055_145, 0.751_874_3],
+];
+
+/// The Bradford chromatic adaptation from the D50 white point to D65.
+const D50_TO_D65: [[f32; 3]; 3] = [
+    [0.955_473_4, -0.023_098_537, 0.063_259_31],
+    [-0.028_369_706, 1.009_995_5, 0.021_041_399],
+    [0.012_314_002, -0.020_507_697, 1.330_366],
+];
+
+/// The D50 reference white used by CIE Lab and LCH, as adapted from D65 by the matrices
+/// above, so that grays have no chroma.
+const D50_WHITE: [f32; 3] = [0.964_321_8, 1.0, 0.824_933_2];
+
+/// Multiplies a 3x3 matrix with a vector.
+fn multiply(matrix: &[[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+/// Removes the sRGB gamma from a channel from 0 to 1, giving linear light.
+fn srgb_to_linear(channel: f32) -> f32 {
+    if channel.abs() <= 0.040_45 {
+        channel / 12.92
+    } else {
+        channel.signum() * ((channel.abs() + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Applies the sRGB gamma to a linear light channel.
+fn linear_to_srgb(channel: f32) -> f32 {
+    if channel.abs() <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        channel.signum() * (1.055 * channel.abs().powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+/// Returns the linear light sRGB channels of a color.
+fn linear_rgb(color: &Color) -> [f32; 3] {
+    [color.r, color.g, color.b].map(|channel| srgb_to_linear(channel as f32 / 255.0))
+}
+
+/// Creates a color from linear light sRGB channels, clipping channels outside the sRGB gamut.
+fn linear_to_color(linear: [f32; 3], a: f32) -> Color {
+    let [r, g, b] = linear.map(linear_to_srgb);
+
+    // Allow for rounding errors of up to half a step of an 8-bit channel.
+    let tolerance = 0.5 / 255.0;
+    let clipped = [r, g, b]
+        .iter()
+        .any(|channel| *channel < -tolerance || *channel > 1.0 + tolerance);
+    let [r, g, b] = [r, g, b].map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8);
+
+    Color {
+        r,
+        g,
+        b,
+        a,
+        clipped,
+    }
+}
+
+/// Converts a color to CIE Lab, relative to the D50 white point.
+fn color_to_lab(color: &Color) -> [f32; 3] {
+    let xyz = multiply(&D65_TO_D50, multiply(&SRGB_TO_XYZ_D65, linear_rgb(color)));
+    let [fx, fy, fz] = [0, 1, 2].map(|i| {
+        let t = xyz[i] / D50_WHITE[i];
+        if t > LAB_EPSILON {
+            t.cbrt()
+        } else {
+            (LAB_KAPPA * t + 16.0) / 116.0
+        }
+    });
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Converts CIE Lab, relative to the D50 white point, to a color.
+fn lab_to_color([l, a, b]: [f32; 3], alpha: f32) -> Color {
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+    let inverse = |f: f32| {
+        if f.powi(3) > LAB_EPSILON {
+            f.powi(3)
+        } else {
+            (116.0 * f - 16.0) / LAB_KAPPA
+        }
+    };
+    let xyz = [
+        inverse(fx) * D50_WHITE[0],
+        if l > LAB_KAPPA * LAB_EPSILON {
+            fy.powi(3)
+        } else {
+            l / LAB_KAPPA
+        } * D50_WHITE[1],
+        inverse(fz) * D50_WHITE[2],
+    ];
+    linear_to_color(
+        multiply(&XYZ_D65_TO_SRGB, multiply(&D50_TO_D65, xyz)),
+        alpha,
+    )
+}
+
+/// The CIE Lab constant ε, below which the lightness curve is linear.
+const LAB_EPSILON: f32 = 216.0 / 24_389.0;
+
+/// The CIE Lab constant κ, the slope of the linear part of the lightness curve.
+const LAB_KAPPA: f32 = 24_389.0 / 27.0;
+
+/// Converts linear light sRGB to OKLab.
+fn linear_to_oklab(linear: [f32; 3]) -> [f32; 3] {
+    let lms = multiply(
+        &[
+            [0.412_221_46, 0.536_332_55, 0.051_445_995],
+            [0.211_903_5, 0.680_699_5, 0.107_396_96],
+            [0.088_302_46, 0.281_718_85, 0.629_978_7],
+        ],
+        linear,
+    );
+    multiply(
+        &[
+            [0.210_454_26, 0.793_617_8, -0.004_072_047],
+            [1.977_998_5, -2.428_592_2, 0.450_593_7],
+            [0.025_904_037, 0.782_771_77, -0.808_675_77],
+        ],
+        lms.map(f32::cbrt),
+    )
+}
+
+/// Converts OKLab to linear light sRGB.
+fn oklab_to_linear(lab: [f32; 3]) -> [f32; 3] {
+    let lms = multiply(
+        &[
+            [1.0, 0.396_337_78, 0.215_803_76],
+            [1.0, -0.105_561_346, -0.063_854_17],
+            [1.0, -0.089_484_18, -1.291_485_5],
+        ],
+        lab,
+    );
+    multiply(
+        &[
+            [4.076_741_7, -3.307_711_6, 0.230_969_94],
+            [-1.268_438, 2.609_757_4, -0.341_319_38],
+            [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+        ],
+        lms.map(|channel| channel.powi(3)),
+    )
+}
+
+/// Converts Lab coordinates to their polar form: lightness, chroma and hue in degrees.
+/// A chroma too small to show with `decimals` decimals is a gray, and gets a zero hue.
+fn to_polar([l, a, b]: [f32; 3], decimals: i32) -> [f32; 3] {
+    let chroma = (a * a + b * b).sqrt();
+    // The hue of a gray is undefined, so it is shown as zero.
+    let hue = if chroma < 0.5 * 10f32.powi(-decimals) {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    };
+    [l, chroma, hue]
+}
+
+/// Converts polar lightness, chroma and hue in degrees back to Lab coordinates.
+fn from_polar([l, c, h]: [f32; 3]) -> [f32; 3] {
+    let (sin, cos) = h.to_radians().sin_cos();
+    [l, c * cos, c * sin]
+}
+
+/// Creates an opaque color from a `0xRRGGBB` value.
+fn color_from_rgb(rgb: u32) -> Color {
+    Color {
+        r: ((rgb >> 16) & 0xFF) as u8,
+        g: ((rgb >> 8) & 0xFF) as u8,
+        b: (rgb & 0xFF) as u8,
+        a: 1.0,
+        clipped: false,
+    }
+}
+
+/// Finds the CSS named color closest to the color, using the "redmean" weighted RGB distance.
+fn nearest_named_color(color: &Color) -> String {
+    let distance = |other: &Color| {
+        let mean_red = (color.r as f32 + other.r as f32) / 2.0;
+        let dr = color.r as f32 - other.r as f32;
+        let dg = color.g as f32 - other.g as f32;
+        let db = color.b as f32 - other.b as f32;
+        ((2.0 + mean_red / 256.0) * dr * dr
+            + 4.0 * dg * dg
+            + (2.0 + (255.0 - mean_red) / 256.0) * db * db)
+            .sqrt()
+    };
+
+    let (name, nearest) = NAMED_COLORS
+        .iter()
+        .map(|(name, rgb)| (*name, color_from_rgb(*rgb)))
+        .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+        .expect("the named color table is not empty");
+
+    if distance(&nearest) == 0.0 {
+        format!("{} (exact match)", name)
+    } else {
+        format!("{} ({}, closest match)", name, convert_to_hex(&nearest))
+    }
+}
+
+/// The 148 CSS named colors, in alphabetical order.
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xF0F8FF),
+    ("antiquewhite", 0xFAEBD7),
+    ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4),
+    ("azure", 0xF0FFFF),
+    ("beige", 0xF5F5DC),
+    ("bisque", 0xFFE4C4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xFFEBCD),
+    ("blue", 0x0000FF),
+    ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A),
+    ("burlywood", 0xDEB887),
+    ("cadetblue", 0x5F9EA0),
+    ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E),
+    ("coral", 0xFF7F50),
+    ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC),
+    ("crimson", 0xDC143C),
+    ("cyan", 0x00FFFF),
+    ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B),
+    ("darkgoldenrod", 0xB8860B),
+    ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xA9A9A9),
+    ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B),
+    ("darkolivegreen", 0x556B2F),
+    ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC),
+    ("darkred", 0x8B0000),
+    ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F),
+    ("darkslateblue", 0x483D8B),
+    ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F),
+    ("darkturquoise", 0x00CED1),
+    ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493),
+    ("deepskyblue", 0x00BFFF),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF),
+    ("firebrick", 0xB22222),
+    ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22),
+    ("fuchsia", 0xFF00FF),
+    ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF),
+    ("gold", 0xFFD700),
+    ("goldenrod", 0xDAA520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xADFF2F),
+    ("grey", 0x808080),
+    ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4),
+    ("indianred", 0xCD5C5C),
+    ("indigo", 0x4B0082),
+    ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C),
+    ("lavender", 0xE6E6FA),
+    ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00),
+    ("lemonchiffon", 0xFFFACD),
+    ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080),
+    ("lightcyan", 0xE0FFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3),
+    ("lightgreen", 0x90EE90),
+    ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1),
+    ("lightsalmon", 0xFFA07A),
+    ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE),
+    ("lightyellow", 0xFFFFE0),
+    ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32),
+    ("linen", 0xFAF0E6),
+    ("magenta", 0xFF00FF),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA),
+    ("mediumblue", 0x0000CD),
+    ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB),
+    ("mediumseagreen", 0x3CB371),
+    ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A),
+    ("mediumturquoise", 0x48D1CC),
+    ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xF5FFFA),
+    ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5),
+    ("navajowhite", 0xFFDEAD),
+    ("navy", 0x000080),
+    ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6B8E23),
+    ("orange", 0xFFA500),
+    ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6),
+    ("palegoldenrod", 0xEEE8AA),
+    ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE),
+    ("palevioletred", 0xDB7093),
+    ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9),
+    ("peru", 0xCD853F),
+    ("pink", 0xFFC0CB),
+    ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F),
+    ("royalblue", 0x4169E1),
+    ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072),
+    ("sandybrown", 0xF4A460),
+    ("seagreen", 0x2E8B57),
+    ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D),
+    ("silver", 0xC0C0C0),
+    ("skyblue", 0x87CEEB),
+    ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xFFFAFA),
+    ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4),
+    ("tan", 0xD2B48C),
+    ("teal", 0x008080),
+    ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("violet", 0xEE82EE),
+    ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF),
+    ("whitesmoke", 0xF5F5F5),
+    ("yellow", 0xFFFF00),
+    ("yellowgreen", 0x9ACD32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn black_converts_to_cmyk_without_nan() {
+        let black = parse_named("black").expect("named color");
+        assert_eq!(convert_to_cmyk(&black), "0%, 0%, 0%, 100%");
+
+        let transparent = parse_named("transparent").expect("named color");
+        assert_eq!(
+            convert_to_cmyk(&transparent),
+            "0%, 0%, 0%, 100%\nAlpha: 0% (not part of CMYK)"
+        );
+    }
 }
@@ -0,0 +1,1009 @@
+use crate::tools::time_zone::Zone;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::Path;
+
+/// Month names accepted in the month field, starting from January.
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Weekday names accepted in the day-of-week field, starting from Sunday.
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Struct to explain cron expressions and calculate their upcoming run times.
+pub struct CronExplainer {
+    /// The input cron expression.
+    pub input: String,
+    /// The plain English explanation of the expression.
+    pub explanation: String,
+    /// The per-field breakdown of the expression, or the location of the invalid field.
+    pub field_breakdown: String,
+    /// The upcoming run times, one per line.
+    pub next_runs: String,
+    /// The number of upcoming run times to calculate.
+    pub run_count: usize,
+    /// The time zone selected for calculating run times.
+    pub time_zone: Zone,
+    /// The time zone the run times were last calculated in (a `CRON_TZ=` prefix overrides the selection).
+    pub active_time_zone: Zone,
+    /// A potential message for tools export.
+    pub tools_export_message: Option<String>,
+}
+
+impl CronExplainer {
+    /// Creates a new instance of `CronExplainer`.
+    pub fn new() -> Self {
+        CronExplainer {
+            input: String::new(),
+            explanation: String::new(),
+            field_breakdown: String::new(),
+            next_runs: String::new(),
+            run_count: 5,
+            time_zone: Zone::Utc,
+            active_time_zone: Zone::Utc,
+            tools_export_message: None,
+        }
+    }
+
+    /// Explains the `input` expression and calculates its upcoming run times.
+    pub fn explain(&mut self) {
+        // Multi-line input is treated as a single expression.
+        let expression = self.input.replace('\n', " ");
+        self.active_time_zone = self.time_zone;
+
+        if expression.trim().eq_ignore_ascii_case("@reboot") {
+            self.explanation = "At startup of the cron daemon".to_string();
+            self.field_breakdown = "@reboot has no time fields".to_string();
+            self.next_runs = "No scheduled runs, @reboot only runs at startup".to_string();
+            return;
+        }
+
+        match parse_expression(&expression) {
+            Ok((schedule, time_zone)) => {
+                if let Some(time_zone) = time_zone {
+                    self.active_time_zone = time_zone;
+                }
+                self.explanation = schedule.describe();
+                self.field_breakdown = schedule.breakdown();
+                self.next_runs = schedule.next_runs(self.active_time_zone, self.run_count);
+            }
+            Err(err) => {
+                // Point at the invalid field below the expression.
+                self.field_breakdown = match err.position {
+                    Some((start, len)) => format!(
+                        "{}\n{}{}",
+                        expression,
+                        " ".repeat(expression[..start].chars().count()),
+                        "^".repeat(expression[start..start + len].chars().count())
+                    ),
+                    None => expression.clone(),
+                };
+                self.explanation = err.message;
+                self.next_runs = "No runs, the expression is invalid".to_string();
+            }
+        }
+    }
+
+    /// Increases the number of upcoming run times to calculate (maximum is 50).
+    pub fn increase_run_count(&mut self) {
+        if self.run_count < 50 {
+            self.run_count += 1;
+        }
+    }
+
+    /// Decreases the number of upcoming run times to calculate (minimum is 1).
+    pub fn decrease_run_count(&mut self) {
+        if self.run_count > 1 {
+            self.run_count -= 1;
+        }
+    }
+
+    /// Switches the run time calculation between UTC and the local time zone.
+    pub fn toggle_time_zone(&mut self) {
+        self.time_zone = match self.time_zone {
+            Zone::Utc => Zone::Local,
+            _ => Zone::Utc,
+        };
+    }
+
+    /// Exports the explanation and upcoming run times to a file.
+    pub fn write_to_file(&self) -> std::io::Result<()> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new("export/cron.txt");
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // Write the expression, explanation, fields and run times to the file.
+        writeln!(file, "Expression: {}", self.input)?;
+        writeln!(file, "Explanation: {}", self.explanation)?;
+        writeln!(file, "\nFields:")?;
+        writeln!(file, "{}", self.field_breakdown)?;
+        writeln!(file, "\nNext Runs ({}):", self.active_time_zone.label())?;
+        writeln!(file, "{}", self.next_runs)?;
+
+        // Return Ok(()) to indicate success.
+        Ok(())
+    }
+}
+
+/// An error found while parsing a cron expression.
+struct CronError {
+    /// The byte offset and length of the offending token in the expression, if known.
+    position: Option<(usize, usize)>,
+    /// A description of the problem.
+    message: String,
+}
+
+/// The fields of a cron expression.
+#[derive(Clone, Copy, PartialEq)]
+enum FieldKind {
+    Second,
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+    Year,
+}
+
+impl FieldKind {
+    /// Returns the name of the field used in messages.
+    fn name(&self) -> &'static str {
+        match self {
+            FieldKind::Second => "second",
+            FieldKind::Minute => "minute",
+            FieldKind::Hour => "hour",
+            FieldKind::DayOfMonth => "day-of-month",
+            FieldKind::Month => "month",
+            FieldKind::DayOfWeek => "day-of-week",
+            FieldKind::Year => "year",
+        }
+    }
+
+    /// Returns the title of the field used in the breakdown.
+    fn title(&self) -> &'static str {
+        match self {
+            FieldKind::Second => "Second",
+            FieldKind::Minute => "Minute",
+            FieldKind::Hour => "Hour",
+            FieldKind::DayOfMonth => "Day of Month",
+            FieldKind::Month => "Month",
+            FieldKind::DayOfWeek => "Day of Week",
+            FieldKind::Year => "Year",
+        }
+    }
+
+    /// Returns the smallest and largest value accepted by the field.
+    fn bounds(&self) -> (u32, u32) {
+        match self {
+            FieldKind::Second | FieldKind::Minute => (0, 59),
+            FieldKind::Hour => (0, 23),
+            FieldKind::DayOfMonth => (1, 31),
+            FieldKind::Month => (1, 12),
+            // Both 0 and 7 mean Sunday.
+            FieldKind::DayOfWeek => (0, 7),
+            FieldKind::Year => (1970, 2099),
+        }
+    }
+
+    /// Returns the unit used when describing steps of the field.
+    fn unit(&self) -> &'static str {
+        match self {
+            FieldKind::Second => "second",
+            FieldKind::Minute => "minute",
+            FieldKind::Hour => "hour",
+            FieldKind::DayOfMonth | FieldKind::DayOfWeek => "day",
+            FieldKind::Month => "month",
+            FieldKind::Year => "year",
+        }
+    }
+
+    /// Parses a single value of the field, accepting month and weekday names.
+    fn parse_value(&self, token: &str) -> Result<u32, String> {
+        let (min, max) = self.bounds();
+        let value = if let Ok(number) = token.parse::<u32>() {
+            number
+        } else {
+            let names: &[&str] = match self {
+                FieldKind::Month => &MONTH_NAMES,
+                FieldKind::DayOfWeek => &WEEKDAY_NAMES,
+                _ => &[],
+            };
+            let offset = if *self == FieldKind::Month { 1 } else { 0 };
+            names
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(token))
+                .map(|index| index as u32 + offset)
+                .ok_or_else(|| format!("'{}' is not a valid {}", token, self.name()))?
+        };
+
+        if value < min || value > max {
+            return Err(format!("value {} is out of range {}-{}", value, min, max));
+        }
+        Ok(value)
+    }
+
+    /// Formats a value of the field for explanations.
+    fn format_value(&self, value: u32) -> String {
+        match self {
+            FieldKind::Hour => format!("{:02}:00", value),
+            FieldKind::Month => month_name(value).to_string(),
+            FieldKind::DayOfWeek => weekday_name(value).to_string(),
+            _ => value.to_string(),
+        }
+    }
+}
+
+/// A single comma separated entry of a cron field.
+enum FieldPart {
+    /// `*` or `?`.
+    Any,
+    /// A single value such as `5`.
+    Value(u32),
+    /// A range such as `1-5`.
+    Range(u32, u32),
+    /// A stepped entry such as `*/15`, `5/10` or `1-30/2`.
+    Step {
+        start: u32,
+        end: u32,
+        step: u32,
+        from_any: bool,
+    },
+}
+
+/// A parsed cron field.
+struct CronField {
+    /// The kind of field.
+    kind: FieldKind,
+    /// The field as written in the expression.
+    expression: String,
+    /// The comma separated entries of the field.
+    parts: Vec<FieldPart>,
+    /// Whether each value (indexed by value) matches the field.
+    allowed: Vec<bool>,
+}
+
+impl CronField {
+    /// Parses a field of the given kind.
+    fn parse(expression: &str, kind: FieldKind) -> Result<CronField, String> {
+        let (min, max) = kind.bounds();
+        // `*` in the day-of-week field covers Sunday once, as 0.
+        let any_max = if kind == FieldKind::DayOfWeek { 6 } else { max };
+        let mut parts = Vec::new();
+        let mut allowed = vec![false; max as usize + 1];
+
+        for item in expression.split(',') {
+            if item.is_empty() {
+                return Err("empty list entry".to_string());
+            }
+
+            // Split off the optional step.
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => {
+                    let step: u32 = step
+                        .parse()
+                        .map_err(|_| format!("step '{}' is not a number", step))?;
+                    if step == 0 {
+                        return Err("step must be greater than zero".to_string());
+                    }
+                    (range, Some(step))
+                }
+                None => (item, None),
+            };
+
+            // Work out the values covered by the entry.
+            let (start, end, part) = if range == "*" || range == "?" {
+                if range == "?" && kind != FieldKind::DayOfMonth && kind != FieldKind::DayOfWeek {
+                    return Err(
+                        "'?' is only allowed in the day-of-month and day-of-week fields"
+                            .to_string(),
+                    );
+                }
+                (min, any_max, FieldPart::Any)
+            } else if let Some((first, last)) = range.split_once('-') {
+                let first = kind.parse_value(first)?;
+                let last = kind.parse_value(last)?;
+                if first > last {
+                    return Err(format!("range {}-{} is backwards", first, last));
+                }
+                (first, last, FieldPart::Range(first, last))
+            } else {
+                let value = kind.parse_value(range)?;
+                match step {
+                    // `5/10` means every 10th value starting at 5.
+                    Some(_) => (value, any_max.max(value), FieldPart::Value(value)),
+                    None => (value, value, FieldPart::Value(value)),
+                }
+            };
+
+            for value in (start..=end).step_by(step.unwrap_or(1) as usize) {
+                allowed[value as usize] = true;
+            }
+
+            parts.push(match step {
+                Some(step) => FieldPart::Step {
+                    start,
+                    end,
+                    step,
+                    from_any: matches!(part, FieldPart::Any),
+                },
+                None => part,
+            });
+        }
+
+        // 7 is an alias for Sunday.
+        if kind == FieldKind::DayOfWeek && allowed[7] {
+            allowed[0] = true;
+            allowed[7] = false;
+        }
+
+        Ok(CronField {
+            kind,
+            expression: expression.to_string(),
+            parts,
+            allowed,
+        })
+    }
+
+    /// Returns whether the field starts with `*` or `?`, which cron uses to decide
+    /// how the two day fields are combined.
+    fn is_star(&self) -> bool {
+        self.expression.starts_with('*') || self.expression.starts_with('?')
+    }
+
+    /// Returns whether the field matches every value (`*` or `?` without a step).
+    fn is_any(&self) -> bool {
+        matches!(self.parts.as_slice(), [FieldPart::Any])
+    }
+
+    /// Returns whether the field matches the given value.
+    fn matches(&self, value: u32) -> bool {
+        self.allowed.get(value as usize).copied().unwrap_or(false)
+    }
+
+    /// Returns the matching values when the field only lists plain values.
+    fn plain_values(&self) -> Option<Vec<u32>> {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                FieldPart::Value(value) => Some(*value),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the values matched by the field.
+    fn values(&self) -> Vec<u32> {
+        (0..self.allowed.len() as u32)
+            .filter(|value| self.matches(*value))
+            .collect()
+    }
+
+    /// Describes the field in plain English.
+    fn describe(&self) -> String {
+        let kind = self.kind;
+        let mut phrases = Vec::new();
+
+        // Plain values are grouped into a single phrase.
+        let values: Vec<String> = self
+            .parts
+            .iter()
+            .filter_map(|part| match part {
+                FieldPart::Value(value) => Some(kind.format_value(*value)),
+                _ => None,
+            })
+            .collect();
+        if !values.is_empty() {
+            let plural = values.len() > 1;
+            let list = join_list(&values);
+            phrases.push(match kind {
+                FieldKind::Second | FieldKind::Minute => format!(
+                    "at {}{} {}",
+                    kind.unit(),
+                    if plural { "s" } else { "" },
+                    list
+                ),
+                FieldKind::Hour => {
+                    format!("during the {} hour{}", list, if plural { "s" } else { "" })
+                }
+                FieldKind::DayOfMonth => format!(
+                    "on day{} {} of the month",
+                    if plural { "s" } else { "" },
+                    list
+                ),
+                FieldKind::Month | FieldKind::Year => format!("in {}", list),
+                FieldKind::DayOfWeek => format!("on {}", list),
+            });
+        }
+
+        for part in &self.parts {
+            match part {
+                FieldPart::Value(_) => {}
+                FieldPart::Any => phrases.push(format!("every {}", kind.unit())),
+                FieldPart::Range(first, last) => phrases.push(match kind {
+                    FieldKind::Second | FieldKind::Minute => {
+                        format!("every {} from {} through {}", kind.unit(), first, last)
+                    }
+                    FieldKind::Hour => format!("between {:02}:00 and {:02}:59", first, last),
+                    FieldKind::DayOfMonth => {
+                        format!("on days {} through {} of the month", first, last)
+                    }
+                    FieldKind::DayOfWeek => format!(
+                        "on {} through {}",
+                        kind.format_value(*first),
+                        kind.format_value(*last)
+                    ),
+                    FieldKind::Month | FieldKind::Year => format!(
+                        "from {} through {}",
+                        kind.format_value(*first),
+                        kind.format_value(*last)
+                    ),
+                }),
+                FieldPart::Step {
+                    start,
+                    end,
+                    step,
+                    from_any,
+                } => {
+                    let mut phrase = if *step == 1 {
+                        format!("every {}", kind.unit())
+                    } else {
+                        format!("every {} {}s", step, kind.unit())
+                    };
+                    if !*from_any {
+                        phrase.push_str(&format!(
+                            " from {} through {}",
+                            kind.format_value(*start),
+                            kind.format_value(*end)
+                        ));
+                    }
+                    phrases.push(phrase);
+                }
+            }
+        }
+
+        join_list(&phrases)
+    }
+}
+
+/// A parsed cron schedule.
+struct CronSchedule {
+    seconds: Option<CronField>,
+    minutes: CronField,
+    hours: CronField,
+    days_of_month: CronField,
+    months: CronField,
+    days_of_week: CronField,
+    years: Option<CronField>,
+}
+
+impl CronSchedule {
+    /// Returns the fields present in the schedule, in expression order.
+    fn fields(&self) -> Vec<&CronField> {
+        let mut fields = Vec::new();
+        fields.extend(self.seconds.as_ref());
+        fields.extend([
+            &self.minutes,
+            &self.hours,
+            &self.days_of_month,
+            &self.months,
+            &self.days_of_week,
+        ]);
+        fields.extend(self.years.as_ref());
+        fields
+    }
+
+    /// Describes the schedule in plain English.
+    fn describe(&self) -> String {
+        let mut phrases = vec![self.describe_time()];
+
+        // When neither day field starts with `*`, cron runs on days matching either one.
+        if !self.days_of_month.is_star() && !self.days_of_week.is_star() {
+            phrases.push(format!(
+                "{} or {}",
+                self.days_of_month.describe(),
+                self.days_of_week.describe()
+            ));
+        } else {
+            if !self.days_of_month.is_any() {
+                phrases.push(self.days_of_month.describe());
+            }
+            if !self.days_of_week.is_any() {
+                phrases.push(self.days_of_week.describe());
+            }
+        }
+        if !self.months.is_any() {
+            phrases.push(self.months.describe());
+        }
+        if let Some(years) = self.years.as_ref().filter(|years| !years.is_any()) {
+            phrases.push(years.describe());
+        }
+
+        capitalize(&phrases.join(", "))
+    }
+
+    /// Describes the time of day part of the schedule.
+    fn describe_time(&self) -> String {
+        let seconds = match &self.seconds {
+            Some(field) => field.plain_values(),
+            None => Some(vec![0]),
+        };
+
+        // Specific times are listed directly when there are only a few of them.
+        if let (Some(seconds), Some(minutes), Some(hours)) = (
+            seconds,
+            self.minutes.plain_values(),
+            self.hours.plain_values(),
+        ) {
+            if seconds.len() * minutes.len() * hours.len() <= 6 {
+                let show_seconds = seconds.iter().any(|second| *second != 0);
+                let mut times = Vec::new();
+                for hour in self.hours.values() {
+                    for minute in self.minutes.values() {
+                        for second in &seconds {
+                            times.push(if show_seconds {
+                                format!("{:02}:{:02}:{:02}", hour, minute, second)
+                            } else {
+                                format!("{:02}:{:02}", hour, minute)
+                            });
+                        }
+                    }
+                }
+                return format!("at {}", join_list(&times));
+            }
+        }
+
+        let mut phrases = Vec::new();
+        if let Some(seconds) = self
+            .seconds
+            .as_ref()
+            .filter(|seconds| seconds.expression != "0")
+        {
+            phrases.push(seconds.describe());
+        }
+        // "Every minute" adds nothing after a seconds phrase.
+        if phrases.is_empty() || !self.minutes.is_any() {
+            phrases.push(self.minutes.describe());
+        }
+        if !self.hours.is_any() {
+            phrases.push(self.hours.describe());
+        }
+        phrases.join(", ")
+    }
+
+    /// Lists each field of the schedule with the values it matches, or their range for a
+    /// field matching every value.
+    fn breakdown(&self) -> String {
+        self.fields()
+            .iter()
+            .map(|field| {
+                let values: Vec<String> = field
+                    .values()
+                    .iter()
+                    .map(|value| match field.kind {
+                        FieldKind::Month | FieldKind::DayOfWeek => {
+                            field.kind.format_value(*value)[..3].to_string()
+                        }
+                        _ => value.to_string(),
+                    })
+                    .collect();
+                let matched = match (field.is_any(), values.first(), values.last()) {
+                    (true, Some(first), Some(last)) => {
+                        format!("every {} ({}-{})", field.kind.unit(), first, last)
+                    }
+                    _ => values.join(", "),
+                };
+                format!(
+                    "{:<13} {:<10} {}",
+                    field.kind.title(),
+                    field.expression,
+                    matched
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns whether the schedule runs on the given date.
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        let day_of_month = self.days_of_month.matches(date.day());
+        let day_of_week = self
+            .days_of_week
+            .matches(date.weekday().num_days_from_sunday());
+
+        if self.days_of_month.is_star() || self.days_of_week.is_star() {
+            day_of_month && day_of_week
+        } else {
+            day_of_month || day_of_week
+        }
+    }
+
+    /// Finds the first run strictly after the given wall clock time.
+    fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        // Without a seconds field the schedule runs at the start of a minute.
+        let mut time = after.with_nanosecond(0)?;
+        time = match self.seconds {
+            Some(_) => time + Duration::seconds(1),
+            None => time.with_second(0)? + Duration::minutes(1),
+        };
+
+        // Schedules repeat every 400 years, so searching further is pointless.
+        let last_year = match self.years {
+            Some(_) => FieldKind::Year.bounds().1 as i32,
+            None => time.year() + 400,
+        };
+
+        while time.year() <= last_year {
+            if let Some(years) = &self.years {
+                if !years.matches(time.year() as u32) {
+                    time = NaiveDate::from_ymd_opt(time.year() + 1, 1, 1)?.and_hms_opt(0, 0, 0)?;
+                    continue;
+                }
+            }
+            if !self.months.matches(time.month()) {
+                let (year, month) = if time.month() == 12 {
+                    (time.year() + 1, 1)
+                } else {
+                    (time.year(), time.month() + 1)
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.matches_date(time.date()) {
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.hours.matches(time.hour()) {
+                time = time.date().and_hms_opt(time.hour(), 0, 0)? + Duration::hours(1);
+                continue;
+            }
+            if !self.minutes.matches(time.minute()) {
+                time = time.with_second(0)? + Duration::minutes(1);
+                continue;
+            }
+            if let Some(seconds) = &self.seconds {
+                if !seconds.matches(time.second()) {
+                    time += Duration::seconds(1);
+                    continue;
+                }
+            }
+            return Some(time);
+        }
+
+        None
+    }
+
+    /// Lists the next `count` run times in the given time zone.
+    fn next_runs(&self, time_zone: Zone, count: usize) -> String {
+        let mut runs = Vec::new();
+        let mut time = time_zone.now();
+
+        while runs.len() < count {
+            match self.next_after(time) {
+                Some(next) => {
+                    time = next;
+                    // Runs falling into a daylight saving gap are skipped, as cron does.
+                    if let Some(run) = time_zone.resolve(next) {
+                        runs.push(run.format("%Y-%m-%d %H:%M:%S %:z (%a)").to_string());
+                    }
+                }
+                None => break,
+            }
+        }
+
+        if runs.is_empty() {
+            "No upcoming runs found".to_string()
+        } else {
+            runs.join("\n")
+        }
+    }
+}
+
+/// Parses a cron expression with an optional `CRON_TZ=` or `TZ=` prefix.
+fn parse_expression(expression: &str) -> Result<(CronSchedule, Option<Zone>), CronError> {
+    // Collect each whitespace separated token with its byte offset.
+    let mut tokens: Vec<(usize, &str)> = Vec::new();
+    let mut start = None;
+    for (index, c) in expression.char_indices() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(index),
+            (true, Some(begin)) => {
+                tokens.push((begin, &expression[begin..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        tokens.push((begin, &expression[begin..]));
+    }
+
+    // Read the time zone prefix.
+    let mut time_zone = None;
+    if let Some(&(offset, token)) = tokens.first() {
+        if let Some(value) = token
+            .strip_prefix("CRON_TZ=")
+            .or_else(|| token.strip_prefix("TZ="))
+        {
+            time_zone = Some(Zone::parse(value).ok_or_else(|| CronError {
+                position: Some((offset, token.len())),
+                message: format!(
+                    "Unsupported time zone '{}', use UTC, Local, an offset such as +05:30 or a zone such as Europe/Paris",
+                    value
+                ),
+            })?);
+            tokens.remove(0);
+        }
+    }
+
+    if tokens.is_empty() {
+        return Err(CronError {
+            position: None,
+            message: "Enter a cron expression such as */15 9-17 * * MON-FRI".to_string(),
+        });
+    }
+
+    // Expand macros such as `@daily`.
+    if tokens.len() == 1 && tokens[0].1.starts_with('@') {
+        let (offset, token) = tokens[0];
+        let expanded = match token.to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            _ => {
+                return Err(CronError {
+                    position: Some((offset, token.len())),
+                    message: format!("Unknown macro '{}'", token),
+                })
+            }
+        };
+        return parse_expression(expanded).map(|(schedule, _)| (schedule, time_zone));
+    }
+
+    let kinds: &[FieldKind] = match tokens.len() {
+        5 => &[
+            FieldKind::Minute,
+            FieldKind::Hour,
+            FieldKind::DayOfMonth,
+            FieldKind::Month,
+            FieldKind::DayOfWeek,
+        ],
+        6 => &[
+            FieldKind::Second,
+            FieldKind::Minute,
+            FieldKind::Hour,
+            FieldKind::DayOfMonth,
+            FieldKind::Month,
+            FieldKind::DayOfWeek,
+        ],
+        7 => &[
+            FieldKind::Second,
+            FieldKind::Minute,
+            FieldKind::Hour,
+            FieldKind::DayOfMonth,
+            FieldKind::Month,
+            FieldKind::DayOfWeek,
+            FieldKind::Year,
+        ],
+        count => {
+            return Err(CronError {
+                position: None,
+                message: format!("Expected 5, 6 or 7 fields but found {}", count),
+            })
+        }
+    };
+
+    // Parse each field, pointing at the first invalid one.
+    let mut fields = Vec::with_capacity(kinds.len());
+    for (&(offset, token), &kind) in tokens.iter().zip(kinds) {
+        fields.push(CronField::parse(token, kind).map_err(|reason| CronError {
+            position: Some((offset, token.len())),
+            message: format!("Invalid {} field '{}': {}", kind.name(), token, reason),
+        })?);
+    }
+
+    let mut fields = fields.into_iter();
+    let seconds = if kinds.len() > 5 { fields.next() } else { None };
+    let schedule = CronSchedule {
+        seconds,
+        minutes: fields.next().unwrap(),
+        hours: fields.next().unwrap(),
+        days_of_month: fields.next().unwrap(),
+        months: fields.next().unwrap(),
+        days_of_week: fields.next().unwrap(),
+        years: fields.next(),
+    };
+
+    Ok((schedule, time_zone))
+}
+
+/// Returns the English name of a month (1-12).
+fn month_name(month: u32) -> &'static str {
+    [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ][(month as usize + 11) % 12]
+}
+
+/// Returns the English name of a weekday (0-7, both 0 and 7 being Sunday).
+fn weekday_name(weekday: u32) -> &'static str {
+    [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ][weekday as usize % 7]
+}
+
+/// Joins items into an English list, e.g. "a, b and c".
+fn join_list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+/// Uppercases the first character of a sentence.
+fn capitalize(sentence: &str) -> String {
+    let mut chars = sentence.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Friday, 22 March 2024 at noon.
+    fn noon() -> NaiveDateTime {
+        at(2024, 3, 22, 12, 0, 0)
+    }
+
+    fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, second))
+            .expect("valid date")
+    }
+
+    fn next(expression: &str, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        parse_expression(expression).ok()?.0.next_after(after)
+    }
+
+    fn error_position(expression: &str) -> Option<(usize, usize)> {
+        parse_expression(expression)
+            .err()
+            .and_then(|err| err.position)
+    }
+
+    #[test]
+    fn day_fields_combine_with_or_unless_one_is_a_star() {
+        // Wednesday the 10th matches the day of month before the next Friday.
+        let saturday = at(2024, 4, 6, 12, 0, 0);
+        assert_eq!(
+            next("0 0 10 * FRI", saturday),
+            Some(at(2024, 4, 10, 0, 0, 0))
+        );
+        assert_eq!(
+            next("0 0 * * FRI", saturday),
+            Some(at(2024, 4, 12, 0, 0, 0))
+        );
+        assert_eq!(next("0 0 10 * *", saturday), Some(at(2024, 4, 10, 0, 0, 0)));
+        assert_eq!(next("0 0 10 * ?", noon()), Some(at(2024, 4, 10, 0, 0, 0)));
+    }
+
+    #[test]
+    fn seven_means_sunday() {
+        assert_eq!(next("0 0 * * 7", noon()), Some(at(2024, 3, 24, 0, 0, 0)));
+        assert_eq!(next("0 0 * * SUN", noon()), Some(at(2024, 3, 24, 0, 0, 0)));
+        assert_eq!(next("0 0 * * 6-7", noon()), Some(at(2024, 3, 23, 0, 0, 0)));
+    }
+
+    #[test]
+    fn value_with_step_starts_at_the_value() {
+        assert_eq!(
+            next("5/10 * * * *", noon()),
+            Some(at(2024, 3, 22, 12, 5, 0))
+        );
+        assert_eq!(
+            next("5/10 * * * *", at(2024, 3, 22, 12, 5, 0)),
+            Some(at(2024, 3, 22, 12, 15, 0))
+        );
+        assert_eq!(
+            next("5/10 * * * *", at(2024, 3, 22, 12, 55, 0)),
+            Some(at(2024, 3, 22, 13, 5, 0))
+        );
+        assert_eq!(
+            next("*/20 * * * * *", noon()),
+            Some(at(2024, 3, 22, 12, 0, 20))
+        );
+    }
+
+    #[test]
+    fn macros_expand_to_their_schedule() {
+        assert_eq!(next("@hourly", noon()), Some(at(2024, 3, 22, 13, 0, 0)));
+        assert_eq!(next("@daily", noon()), Some(at(2024, 3, 23, 0, 0, 0)));
+        assert_eq!(next("@weekly", noon()), Some(at(2024, 3, 24, 0, 0, 0)));
+        assert_eq!(next("@monthly", noon()), Some(at(2024, 4, 1, 0, 0, 0)));
+        assert_eq!(next("@YEARLY", noon()), Some(at(2025, 1, 1, 0, 0, 0)));
+        assert_eq!(error_position("@often"), Some((0, 6)));
+    }
+
+    #[test]
+    fn time_zone_prefix_is_read_before_the_fields() {
+        let (schedule, time_zone) = parse_expression("CRON_TZ=UTC 30 9 * * *")
+            .ok()
+            .expect("valid expression");
+        assert_eq!(time_zone.map(|zone| zone.label()), Some("UTC".to_string()));
+        assert_eq!(schedule.next_after(noon()), Some(at(2024, 3, 23, 9, 30, 0)));
+
+        let (_, time_zone) = parse_expression("TZ=+05:30 @daily")
+            .ok()
+            .expect("valid expression");
+        assert_eq!(
+            time_zone.map(|zone| zone.label()),
+            Some("UTC+05:30".to_string())
+        );
+    }
+
+    #[test]
+    fn years_limit_the_runs() {
+        assert_eq!(
+            next("0 0 0 1 1 * 2030", noon()),
+            Some(at(2030, 1, 1, 0, 0, 0))
+        );
+        assert_eq!(next("0 0 0 1 1 * 2020", noon()), None);
+        assert_eq!(next("0 0 30 2 *", noon()), None);
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_token() {
+        assert_eq!(error_position("0 25 * * *"), Some((2, 2)));
+        assert_eq!(error_position("0 0 * JAN-FOO *"), Some((6, 7)));
+        assert_eq!(error_position("*/0 * * * *"), Some((0, 3)));
+        assert_eq!(error_position("0 ? * * *"), Some((2, 1)));
+        assert_eq!(error_position("CRON_TZ=Mars/Base 0 * * * *"), Some((0, 17)));
+        assert_eq!(error_position("0 0 * *"), None);
+        assert_eq!(error_position("CRON_TZ=UTC"), None);
+    }
+
+    #[test]
+    fn breakdown_summarizes_fields_matching_every_value() {
+        let (schedule, _) = parse_expression("* * * * * * *")
+            .ok()
+            .expect("valid expression");
+        let breakdown = schedule.breakdown();
+        assert!(breakdown.contains("every second (0-59)"));
+        assert!(breakdown.contains("every day (Sun-Sat)"));
+        assert!(breakdown.contains("every year (1970-2099)"));
+
+        let (schedule, _) = parse_expression("0 9 * * MON-FRI")
+            .ok()
+            .expect("valid expression");
+        assert!(schedule.breakdown().contains("Mon, Tue, Wed, Thu, Fri"));
+    }
+}
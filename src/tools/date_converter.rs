@@ -1,24 +1,280 @@
-use chrono::{DateTime, Datelike, NaiveDateTime, TimeZone, Utc};
-use std::i32;
+use crate::tools::time_zone::Zone;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{
+    DateTime, Datelike, Days, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
+
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::Path;
+
+/// The format used for the human-readable form of a date.
+pub const HUMAN_READABLE_FORMAT: &str = "%A, %B %d, %Y, %I:%M:%S %p";
+
+/// The zones the date is shown in when the input has no `ZONES=` line, after local time and UTC.
+const DEFAULT_DISPLAY_ZONES: [Tz; 4] = [
+    Tz::America__New_York,
+    Tz::Europe__London,
+    Tz::Asia__Kolkata,
+    Tz::Asia__Tokyo,
+];
+
+/// The unit a Unix timestamp is counted in.
+#[derive(Clone, Copy, PartialEq)]
+pub enum TimestampPrecision {
+    /// Detects the unit from the magnitude of the timestamp.
+    Auto,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl TimestampPrecision {
+    /// Returns the name of the precision, as shown in the UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimestampPrecision::Auto => "Auto Detect",
+            TimestampPrecision::Seconds => "Seconds",
+            TimestampPrecision::Milliseconds => "Milliseconds",
+            TimestampPrecision::Microseconds => "Microseconds",
+            TimestampPrecision::Nanoseconds => "Nanoseconds",
+        }
+    }
+
+    /// Returns the number of nanoseconds in one unit of the precision.
+    fn nanoseconds(&self) -> i128 {
+        match self {
+            TimestampPrecision::Auto | TimestampPrecision::Seconds => 1_000_000_000,
+            TimestampPrecision::Milliseconds => 1_000_000,
+            TimestampPrecision::Microseconds => 1_000,
+            TimestampPrecision::Nanoseconds => 1,
+        }
+    }
+
+    /// Detects the precision of a timestamp from its magnitude: timestamps below 10^11 are read
+    /// as seconds (up to the year 5138), below 10^14 as milliseconds, below 10^17 as
+    /// microseconds and larger ones as nanoseconds.
+    fn detect(whole: i128) -> TimestampPrecision {
+        match whole.unsigned_abs() {
+            0..=99_999_999_999 => TimestampPrecision::Seconds,
+            100_000_000_000..=99_999_999_999_999 => TimestampPrecision::Milliseconds,
+            100_000_000_000_000..=99_999_999_999_999_999 => TimestampPrecision::Microseconds,
+            _ => TimestampPrecision::Nanoseconds,
+        }
+    }
+}
+
+/// The order day and month are read in, in dates such as `03/04/2024`.
+#[derive(Clone, Copy, PartialEq)]
+pub enum DateOrder {
+    /// `DD/MM/YYYY`, as in most of Europe.
+    DayFirst,
+    /// `MM/DD/YYYY`, as in the United States.
+    MonthFirst,
+}
+
+impl DateOrder {
+    /// Returns the name of the order, as shown in the UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DateOrder::DayFirst => "DD/MM/YYYY",
+            DateOrder::MonthFirst => "MM/DD/YYYY",
+        }
+    }
+}
+
+/// The calculation the date converter performs on its input.
+#[derive(Clone, Copy, PartialEq)]
+pub enum DateMode {
+    /// Converts a date to all supported formats.
+    Convert,
+    /// Adds a duration to a date, or measures the time between two dates.
+    Arithmetic,
+}
+
+impl DateMode {
+    /// Returns the name of the mode, as shown in the UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DateMode::Convert => "Convert",
+            DateMode::Arithmetic => "Date Arithmetic",
+        }
+    }
+}
+
+/// A named strftime template, whose output is shown as an extra row.
+#[derive(Clone)]
+pub struct CustomFormat {
+    /// The name the output is shown under.
+    pub name: String,
+    /// The strftime template, e.g. `%d %b %Y`.
+    pub template: String,
+}
+
+/// A month of the calendar view, with the selected date.
+pub struct CalendarMonth {
+    /// The month and year, e.g. `March 2025`.
+    pub title: String,
+    /// The ISO 8601 week, day of the year and quarter of the selected date, one per line.
+    pub summary: Vec<String>,
+    /// The selected date.
+    pub selected: NaiveDate,
+    /// Today's date, in the offset of the selected date.
+    pub today: NaiveDate,
+    /// Each week of the month from Monday to Sunday: its ISO 8601 week number and its days,
+    /// `None` for days outside the month.
+    pub weeks: Vec<(u32, [Option<NaiveDate>; 7])>,
+}
+
+/// The name of a custom format given without one, e.g. `FORMAT=%d %b %Y`.
+const UNNAMED_FORMAT: &str = "Custom Format";
+
+/// The strftime specifiers listed in the format reference, along with their description.
+const FORMAT_SPECIFIERS: [(&str, &str); 40] = [
+    ("%Y", "Year"),
+    ("%C", "Century"),
+    ("%y", "Year, 2 digits"),
+    ("%m", "Month, 01-12"),
+    ("%b", "Month, abbreviated"),
+    ("%B", "Month, full name"),
+    ("%d", "Day of month, 01-31"),
+    ("%e", "Day of month, space padded"),
+    ("%j", "Day of year, 001-366"),
+    ("%a", "Weekday, abbreviated"),
+    ("%A", "Weekday, full name"),
+    ("%u", "Weekday, Monday = 1"),
+    ("%w", "Weekday, Sunday = 0"),
+    ("%G", "ISO 8601 week year"),
+    ("%V", "ISO 8601 week, 01-53"),
+    ("%U", "Week, from Sunday"),
+    ("%W", "Week, from Monday"),
+    ("%H", "Hour, 00-23"),
+    ("%I", "Hour, 01-12"),
+    ("%k", "Hour, space padded"),
+    ("%l", "Hour 1-12, space padded"),
+    ("%M", "Minute"),
+    ("%S", "Second"),
+    ("%f", "Nanoseconds"),
+    ("%.3f", "Milliseconds, with a dot"),
+    ("%.f", "Fraction of a second"),
+    ("%p", "AM or PM"),
+    ("%P", "am or pm"),
+    ("%z", "Offset, +hhmm"),
+    ("%:z", "Offset, +hh:mm"),
+    ("%Z", "Zone, shown as its offset"),
+    ("%s", "Unix timestamp"),
+    ("%D", "Same as %m/%d/%y"),
+    ("%F", "Same as %Y-%m-%d"),
+    ("%T", "Same as %H:%M:%S"),
+    ("%R", "Same as %H:%M"),
+    ("%c", "Date and time"),
+    ("%x", "Date"),
+    ("%X", "Time"),
+    ("%%", "A literal %"),
+];
+
+/// Dates with an offset, along with the name of their format.
+const OFFSET_FORMATS: [(&str, &str); 2] = [
+    ("%Y-%m-%d %H:%M:%S%.f%:z", "ISO 8601"), // 2024-03-22 10:00:00-05:00
+    ("%Y%m%dT%H%M%S%.f%z", "ISO 8601 (Basic)"), // 20240322T100000-0500
+];
+
+/// Dates without a time, along with the name of their format. `DD/MM/YYYY` and `MM/DD/YYYY`
+/// are tried in the preferred order first.
+const DATE_FORMATS: [(&str, &str); 13] = [
+    ("%Y-%m-%d", "ISO 8601"),                  // 2024-03-22
+    ("%Y%m%d", "ISO 8601 (Basic)"),            // 20240322
+    ("%G-W%V-%u", "ISO 8601 Week Date"),       // 2024-W12-5
+    ("%GW%V%u", "ISO 8601 Week Date (Basic)"), // 2024W125
+    ("%Y-%j", "ISO 8601 Ordinal Date"),        // 2024-082
+    ("%Y/%m/%d", "YYYY/MM/DD"),                // 2024/03/22
+    ("%d.%m.%Y", "DD.MM.YYYY"),                // 22.03.2024
+    ("%A, %B %d, %Y", "Human Readable"),       // Friday, March 22, 2024
+    ("%B %d, %Y", "Month Name"),               // March 22, 2024
+    ("%B %d %Y", "Month Name"),                // Mar 22 2024
+    ("%A, %d %B %Y", "Month Name"),            // Friday, 22 March 2024
+    ("%d %B %Y", "Month Name"),                // 22 March 2024
+    ("%A %B %d %Y", "Month Name"),             // Fri Mar 22 2024
+];
+
+/// Times that may follow a date, or be given on their own for today. An hour on its own, such
+/// as `9 am`, is read as `9:00 am`.
+const TIME_FORMATS: [&str; 8] = [
+    "%H:%M:%S%.f",
+    "%H:%M",
+    "%I:%M:%S %p",
+    "%I:%M %p",
+    "%H%M%S%.f",
+    "%H%M",
+    "%I:%M:%S%p",
+    "%I:%M%p",
+];
 
 /// Struct for converting dates between different formats.
 pub struct DateConverter {
     /// The input date string.
     pub input: String,
+    /// The calculation performed on the input.
+    pub mode: DateMode,
     /// The converted date in RFC 3339 format.
     pub rfc3339: String,
     /// The converted date in RFC 2822 format.
     pub rfc2822: String,
     /// The converted date in ISO 8601 format.
     pub iso8601: String,
-    /// The converted date as a Unix timestamp (integer).
+    /// The converted date as a Unix timestamp in seconds, with a fraction for sub-second dates.
     pub unix_timestamp: String,
+    /// The converted date as a Unix timestamp in milliseconds.
+    pub unix_milliseconds: String,
+    /// The converted date as a Unix timestamp in microseconds.
+    pub unix_microseconds: String,
+    /// The converted date as a Unix timestamp in nanoseconds.
+    pub unix_nanoseconds: String,
+    /// The unit timestamp input is counted in.
+    pub precision: TimestampPrecision,
+    /// The unit the last timestamp input was read in, `None` when the input was not a timestamp.
+    pub detected_precision: Option<TimestampPrecision>,
+    /// The order day and month are read in when a date could be either.
+    pub date_order: DateOrder,
+    /// The name of the format the input was recognized as.
+    pub detected_format: String,
     /// The converted date in a human-readable format.
     pub human_readable: String,
+    /// How far the date is from now, e.g. `3 days ago` or `in 2 weeks`.
+    pub relative: String,
     /// The converted date in a short date format.
     pub short_date: String,
     /// The converted time in a time-only format.
     pub time_only: String,
+    /// The time zone the date was entered in, with its offset at that date.
+    pub input_zone: String,
+    /// The zones the date is shown in: local time, UTC and those of a `ZONES=` line.
+    pub display_zones: Vec<Zone>,
+    /// The date in each of the display zones, one per line.
+    pub zone_times: String,
+    /// The labelled results of the date arithmetic mode, e.g. `("ISO 8601", "P1Y2M3DT4H")`.
+    pub arithmetic: Result<Vec<(&'static str, String)>, String>,
+    /// The custom format of a `FORMAT=` line, previewed as it is typed.
+    pub format_template: Option<CustomFormat>,
+    /// The custom formats saved with `Alt + s`, shown for every date.
+    pub saved_formats: Vec<CustomFormat>,
+    /// The date in each saved custom format and the previewed one: label, template and output.
+    pub custom_outputs: Vec<(String, String, String)>,
+    /// Whether the reference of strftime specifiers is shown.
+    pub show_format_reference: bool,
+    /// The last date converted, used for the examples of the format reference.
+    datetime: Option<DateTime<FixedOffset>>,
+    /// A potential message for tools export.
+    pub tools_export_message: Option<String>,
+    /// Whether the calendar view is shown, the arrow keys then moving the date.
+    pub show_calendar: bool,
+    /// The zone the last date converted was entered in, kept when the calendar moves the date.
+    entered_zone: Option<Zone>,
+    /// Whether the world clock is shown, ticking every second.
+    pub show_clock: bool,
 }
 
 impl DateConverter {
@@ -26,24 +282,95 @@ impl DateConverter {
     pub fn new() -> Self {
         DateConverter {
             input: String::new(),
+            mode: DateMode::Convert,
             rfc3339: String::new(),
             rfc2822: String::new(),
             iso8601: String::new(),
             unix_timestamp: String::new(),
+            unix_milliseconds: String::new(),
+            unix_microseconds: String::new(),
+            unix_nanoseconds: String::new(),
+            precision: TimestampPrecision::Auto,
+            detected_precision: None,
+            date_order: DateOrder::DayFirst,
+            detected_format: String::new(),
             human_readable: String::new(),
+            relative: String::new(),
             short_date: String::new(),
             time_only: String::new(),
+            input_zone: String::new(),
+            display_zones: Vec::new(),
+            zone_times: String::new(),
+            arithmetic: Ok(Vec::new()),
+            format_template: None,
+            saved_formats: Vec::new(),
+            custom_outputs: Vec::new(),
+            show_format_reference: false,
+            datetime: None,
+            tools_export_message: None,
+            show_calendar: false,
+            entered_zone: None,
+            show_clock: false,
         }
     }
 
     /// Convert the `input` date string to all supported formats.
     pub fn convert_all(&mut self) {
-        // Parse the input string into a DateTime<Utc> object.
-        let parsed_datetime = self.parse_input();
+        // A `ZONES=` line lists the zones to show the date in, a `FORMAT=` line holds a custom
+        // format to preview, other lines hold the date.
+        let input = self.input.clone();
+        let mut date_lines = Vec::new();
+        let mut zones = None;
+        self.format_template = None;
+        for line in input.lines() {
+            let line = line.trim();
+            if let Some(value) = line
+                .strip_prefix("ZONES=")
+                .or_else(|| line.strip_prefix("zones="))
+            {
+                zones = Some(value.to_string());
+            } else if let Some(format) = parse_format_line(line) {
+                self.format_template = Some(format);
+            } else {
+                date_lines.push(line);
+            }
+        }
+        let display_zones = match zones {
+            Some(zones) => parse_zone_list(&zones),
+            None => Ok(DEFAULT_DISPLAY_ZONES
+                .iter()
+                .map(|tz| Zone::Named(*tz))
+                .collect()),
+        };
+        match display_zones {
+            Ok(zones) => {
+                self.display_zones = vec![Zone::Local, Zone::Utc];
+                self.display_zones.extend(zones);
+            }
+            Err(err) => {
+                self.display_zones = Vec::new();
+                self.set_all_to(&err);
+                return;
+            }
+        }
+
+        // Parse the input string into a DateTime<FixedOffset> object, keeping its offset.
+        // Relative dates such as `2 hours ago` are read from a single reading of the clock.
+        let now = Utc::now();
+        self.detected_precision = None;
+        if self.mode == DateMode::Arithmetic {
+            self.calculate(&date_lines, now);
+            return;
+        }
+
+        let date = date_lines.join(" ");
+        let parsed_datetime = self.parse_input(date.trim(), now);
         match parsed_datetime {
-            Ok(datetime) => {
+            Ok((datetime, zone)) => {
                 // If parsing is successful, convert the datetime to all supported formats.
                 self.convert_from_datetime(datetime);
+                self.convert_to_zones(datetime, zone);
+                self.relative = describe_relative(datetime.with_timezone(&Utc) - now);
             }
             Err(err) => {
                 // If parsing fails, set all output fields to the error message.
@@ -52,63 +379,274 @@ impl DateConverter {
         }
     }
 
-    /// Parses the `input` string into a `DateTime<Utc>` object.
-    /// Returns an error message if parsing fails.
-    fn parse_input(&self) -> Result<DateTime<Utc>, String> {
-        // Attempt to parse the input as a Unix timestamp (integer).
-        if let Ok(timestamp) = self.input.parse::<i64>() {
-            // Check if the timestamp is within the supported range (i32::MIN to i32::MAX).
-            if timestamp < i32::MIN as i64 || timestamp > i32::MAX as i64 {
-                return Err("Timestamp out of supported range".to_string());
+    /// Adds the duration on the second line to the date on the first line, or measures the time
+    /// between the dates on both lines. The resulting date, or the second date, is converted to
+    /// all supported formats.
+    fn calculate(&mut self, lines: &[&str], now: DateTime<Utc>) {
+        let lines: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|line| !line.is_empty())
+            .collect();
+        let result = match lines.as_slice() {
+            [first, second] => self.calculate_lines(first, second, now),
+            _ => Err(
+                "Enter a date on the first line, then a second date or a duration such as P45D or -PT4H"
+                    .to_string(),
+            ),
+        };
+
+        match result {
+            Ok((datetime, zone, results)) => {
+                self.convert_from_datetime(datetime);
+                self.convert_to_zones(datetime, zone);
+                self.relative = describe_relative(datetime.with_timezone(&Utc) - now);
+                self.arithmetic = Ok(results);
+            }
+            Err(err) => {
+                self.set_all_to(&err);
+                self.arithmetic = Err(err);
             }
+        }
+    }
+
+    /// Calculates the results of the date arithmetic mode from its two input lines. Returns the
+    /// date to convert, the zone it is shown in and the labelled results.
+    #[allow(clippy::type_complexity)]
+    fn calculate_lines(
+        &mut self,
+        first: &str,
+        second: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(DateTime<FixedOffset>, Zone, Vec<(&'static str, String)>), String> {
+        let (start, zone) = self.parse_input(first, now)?;
+        let start_format = self.detected_format.clone();
+        let from = format!("{} ({})", start.to_rfc3339(), start_format);
 
-            // If the timestamp is valid, create a DateTime<Utc> from it.
-            return Utc
-                .timestamp_opt(timestamp, 0)
-                .single()
-                .ok_or_else(|| "Invalid timestamp".to_string());
+        // A duration on the second line is added to the date: months and days on the wall clock
+        // of its zone, then hours, minutes and seconds as an exact duration.
+        if let Some((direction, shift)) = parse_duration(second) {
+            let out_of_range = || "Year out of supported range (1-9999)".to_string();
+            let naive = zone.convert(start.with_timezone(&Utc)).naive_local();
+            let shifted = shift
+                .apply_calendar(naive, direction)
+                .ok_or_else(out_of_range)?;
+            let shifted = zone.resolve(shifted).ok_or_else(|| {
+                format!(
+                    "{} does not exist in {}, it is skipped by daylight saving time",
+                    shifted.format("%Y-%m-%d %H:%M:%S"),
+                    zone.label()
+                )
+            })?;
+            let shifted = if direction < 0 {
+                shifted.checked_sub_signed(shift.duration)
+            } else {
+                shifted.checked_add_signed(shift.duration)
+            }
+            .ok_or_else(out_of_range)?;
+            let end = zone.convert(shifted.with_timezone(&Utc));
+            check_year(end.year())?;
+            let duration =
+                format_iso_duration(shift.months, shift.days, shift.duration, direction < 0);
+            self.detected_format = format!("{} Shifted by {}", start_format, duration);
+            let results = vec![
+                ("From", from),
+                (
+                    if direction < 0 { "Subtract" } else { "Add" },
+                    format!(
+                        "{} ({})",
+                        duration.trim_start_matches('-'),
+                        describe_duration(shift.months, shift.days, shift.duration)
+                    ),
+                ),
+                ("Result", end.to_rfc3339()),
+                (
+                    "Human Readable",
+                    end.format(HUMAN_READABLE_FORMAT).to_string(),
+                ),
+            ];
+            return Ok((end, zone, results));
         }
 
-        // Define a list of supported date and time formats.
-        let formats = [
-            "%Y-%m-%d %H:%M:%S",    //  2024-03-22 10:00:00
-            "%Y-%m-%dT%H:%M:%S%:z", // 2024-03-22T10:00:00-05:00
-            "%d/%m/%Y %H:%M:%S",    // 22/03/2024 10:00:00
-            "%Y-%m-%d",             // 2024-03-22
-            "%d/%m/%Y",             // 22/03/2024
+        // Otherwise the second line is a date, and the time between both dates is measured.
+        let (end, end_zone) = self.parse_input(second, now)?;
+        let to = format!("{} ({})", end.to_rfc3339(), self.detected_format);
+        self.detected_format = format!("{} to {}", start_format, self.detected_format);
+
+        // Calendar months are counted on the wall clock of the first date.
+        let negative = end < start;
+        let (earlier, later) = if negative { (end, start) } else { (start, end) };
+        let earlier_naive = earlier.with_timezone(start.offset()).naive_local();
+        let later_naive = later.with_timezone(start.offset()).naive_local();
+        let (months, days, time) = calendar_difference(earlier_naive, later_naive);
+        let backwards = if negative { " (Backwards)" } else { "" };
+
+        let difference = end - start;
+        let nanoseconds =
+            difference.num_seconds() as i128 * 1_000_000_000 + difference.subsec_nanos() as i128;
+        let (weeks, week_days) = (
+            difference.num_days().abs() / 7,
+            difference.num_days().abs() % 7,
+        );
+        let weeks = match week_days {
+            0 => format!(
+                "{} week{}{}",
+                weeks,
+                if weeks == 1 { "" } else { "s" },
+                backwards
+            ),
+            _ => format!(
+                "{} week{} and {} day{}{}",
+                weeks,
+                if weeks == 1 { "" } else { "s" },
+                week_days,
+                if week_days == 1 { "" } else { "s" },
+                backwards
+            ),
+        };
+        let results = vec![
+            ("From", from),
+            ("To", to),
+            (
+                "Duration",
+                format!("{}{}", describe_duration(months, days, time), backwards),
+            ),
+            (
+                "ISO 8601",
+                format_iso_duration(months, days, time, negative),
+            ),
+            ("Total Days", format_total(nanoseconds, 86_400)),
+            ("Total Hours", format_total(nanoseconds, 3_600)),
+            ("Total Minutes", format_total(nanoseconds, 60)),
+            ("Total Seconds", format_scaled(nanoseconds, 1_000_000_000)),
+            ("Weeks", weeks),
+            (
+                "Business Days",
+                format!(
+                    "{} (Monday to Friday, from the first date up to the second)",
+                    business_days(
+                        start.date_naive(),
+                        end.with_timezone(start.offset()).date_naive()
+                    )
+                ),
+            ),
         ];
+        Ok((end, end_zone, results))
+    }
 
-        // Attempt to parse the input using each supported format.
-        for format in &formats {
-            if let Ok(naive_datetime) = NaiveDateTime::parse_from_str(&self.input, format) {
-                let year = naive_datetime.year();
-                // Check if the year is within the supported range (1-9999).
-                if year < 1 || year > 9999 {
-                    return Err("Year out of supported range (1-9999)".to_string());
-                }
+    /// Parses the `input` string into a `DateTime<FixedOffset>` object, along with the time zone
+    /// it was entered in. Dates without an offset or a trailing zone, such as `Asia/Kolkata`,
+    /// are read as UTC. Returns an error message if parsing fails.
+    fn parse_input(
+        &mut self,
+        input: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(DateTime<FixedOffset>, Zone), String> {
+        // Attempt to parse the input as a Unix timestamp, optionally with a fraction. Unless a
+        // timestamp precision is picked, 8 digits forming a valid date such as `20240322` are
+        // read as a basic ISO 8601 date instead.
+        let basic_date = self.precision == TimestampPrecision::Auto
+            && input.len() == 8
+            && input.chars().all(|c| c.is_ascii_digit())
+            && NaiveDate::parse_from_str(input, "%Y%m%d").is_ok();
+        if let Some(timestamp) = parse_timestamp(input, self.precision).filter(|_| !basic_date) {
+            let (datetime, precision) = timestamp?;
+            self.detected_precision = Some(precision);
+            self.detected_format = format!("Unix Timestamp ({})", precision.label());
+            return Ok((datetime.fixed_offset(), Zone::Utc));
+        }
 
-                // Create a DateTime<Utc> from the parsed NaiveDateTime.
-                return Ok(Utc.from_utc_datetime(&naive_datetime));
-            }
+        // Keep the offset of dates that carry one, e.g. 2024-03-22T10:00:00-05:00.
+        let offset_datetime = DateTime::parse_from_rfc3339(input)
+            .map(|datetime| (datetime, "RFC 3339"))
+            .or_else(|_| DateTime::parse_from_rfc2822(input).map(|datetime| (datetime, "RFC 2822")))
+            .or_else(|err| {
+                OFFSET_FORMATS
+                    .iter()
+                    .find_map(|(format, label)| {
+                        DateTime::parse_from_str(input, format)
+                            .ok()
+                            .map(|datetime| (datetime, *label))
+                    })
+                    .ok_or(err)
+            });
+        if let Ok((datetime, label)) = offset_datetime {
+            check_year(datetime.year())?;
+            self.detected_format = label.to_string();
+            return Ok((datetime, Zone::Fixed(*datetime.offset())));
         }
 
-        // If parsing as a full date-time fails, try parsing as a date-only format.
-        if let Ok(naive_date) = chrono::NaiveDate::parse_from_str(&self.input, "%Y-%m-%d") {
-            let naive_datetime = naive_date.and_hms_opt(0, 0, 0).unwrap();
-            let year = naive_datetime.year();
-            // Check if the year is within the supported range.
-            if year < 1 || year > 9999 {
-                return Err("Year out of supported range (1-9999)".to_string());
+        // A trailing zone name or offset selects the zone the date is read in, a `Z` stands for UTC.
+        let zone_suffix = input
+            .rsplit_once(' ')
+            .and_then(|(date, zone)| Zone::parse(zone.trim()).map(|zone| (date, zone)))
+            .or_else(|| {
+                input
+                    .strip_suffix('Z')
+                    .filter(|date| date.ends_with(|c: char| c.is_ascii_digit()))
+                    .map(|date| (date, Zone::Utc))
+            });
+        if let Some((date, time_zone)) = zone_suffix {
+            let zone_now = time_zone.convert(now).naive_local();
+            if let Ok((naive, label)) = parse_naive(date.trim(), self.date_order, zone_now) {
+                self.detected_format = format!("{} with Time Zone", label);
+                return time_zone
+                    .resolve(naive)
+                    .map(|datetime| (datetime, time_zone))
+                    .ok_or_else(|| {
+                        format!(
+                            "{} does not exist in {}, it is skipped by daylight saving time",
+                            naive.format("%Y-%m-%d %H:%M:%S"),
+                            time_zone.label()
+                        )
+                    });
             }
-            return Ok(Utc.from_utc_datetime(&naive_datetime));
         }
 
-        // If all parsing attempts fail, return an error message indicating an unrecognized format.
-        Err("Unrecognized date-time format".to_string())
+        // Dates without an offset are read as UTC.
+        let (naive_datetime, label) = parse_naive(input, self.date_order, now.naive_utc())?;
+        self.detected_format = label;
+        Ok((
+            Utc.from_utc_datetime(&naive_datetime).fixed_offset(),
+            Zone::Utc,
+        ))
+    }
+
+    /// Shows the date in each of the display zones, and the zone it was entered in.
+    fn convert_to_zones(&mut self, datetime: DateTime<FixedOffset>, zone: Zone) {
+        self.entered_zone = Some(zone);
+        let utc = datetime.with_timezone(&Utc);
+        self.input_zone = match zone {
+            Zone::Named(_) | Zone::Local => {
+                format!("{} ({})", zone_name(zone), describe_offset(zone, utc))
+            }
+            _ => describe_offset(zone, utc),
+        };
+
+        let width = self
+            .display_zones
+            .iter()
+            .map(|zone| zone_name(*zone).len())
+            .max()
+            .unwrap_or(0);
+        self.zone_times = self
+            .display_zones
+            .iter()
+            .map(|zone| {
+                format!(
+                    "{:<width$}  {}  {}",
+                    zone_name(*zone),
+                    zone.convert(utc).format("%a, %d %b %Y %H:%M:%S"),
+                    describe_offset(*zone, utc),
+                    width = width
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
     }
 
-    /// Converts `DateTime<Utc>` object to all supported formats.
-    fn convert_from_datetime(&mut self, datetime: DateTime<Utc>) {
+    /// Converts `DateTime<FixedOffset>` object to all supported formats, in its own offset.
+    fn convert_from_datetime(&mut self, datetime: DateTime<FixedOffset>) {
         self.convert_to_rfc3339(datetime);
         self.convert_to_rfc2822(datetime);
         self.convert_to_iso8601(datetime);
@@ -116,41 +654,486 @@ impl DateConverter {
         self.convert_to_humanreadable(datetime);
         self.convert_to_shortdate(datetime);
         self.convert_to_timeonly(datetime);
+        self.convert_to_custom_formats(datetime);
     }
 
     /// Converts the given datetime to RFC3339 format.
-    fn convert_to_rfc3339(&mut self, datetime: DateTime<Utc>) {
+    fn convert_to_rfc3339(&mut self, datetime: DateTime<FixedOffset>) {
         self.rfc3339 = datetime.to_rfc3339();
     }
 
     /// Converts the given datetime to RFC2822 format.
-    fn convert_to_rfc2822(&mut self, datetime: DateTime<Utc>) {
+    fn convert_to_rfc2822(&mut self, datetime: DateTime<FixedOffset>) {
         self.rfc2822 = datetime.to_rfc2822();
     }
 
     /// Converts the given datetime to ISO8601 format.
-    fn convert_to_iso8601(&mut self, datetime: DateTime<Utc>) {
-        self.iso8601 = datetime.format("%Y-%m-%dT%H:%M:%S%:z").to_string();
+    fn convert_to_iso8601(&mut self, datetime: DateTime<FixedOffset>) {
+        self.iso8601 = datetime.format("%Y-%m-%dT%H:%M:%S%.f%:z").to_string();
+    }
+
+    /// Converts the given datetime to a Unix timestamp in every precision.
+    fn convert_to_unixtimestamp(&mut self, datetime: DateTime<FixedOffset>) {
+        let nanoseconds = datetime.timestamp() as i128 * 1_000_000_000
+            + datetime.timestamp_subsec_nanos() as i128;
+        self.unix_timestamp = format_scaled(nanoseconds, 1_000_000_000);
+        self.unix_milliseconds = format_scaled(nanoseconds, 1_000_000);
+        self.unix_microseconds = format_scaled(nanoseconds, 1_000);
+        self.unix_nanoseconds = nanoseconds.to_string();
+    }
+
+    /// Switches the order day and month are read and shown in, between `DD/MM/YYYY` and `MM/DD/YYYY`.
+    pub fn toggle_date_order(&mut self) {
+        self.date_order = match self.date_order {
+            DateOrder::DayFirst => DateOrder::MonthFirst,
+            DateOrder::MonthFirst => DateOrder::DayFirst,
+        };
     }
 
-    /// Converts the given datetime to Unix timestamp (integer).
-    fn convert_to_unixtimestamp(&mut self, datetime: DateTime<Utc>) {
-        self.unix_timestamp = datetime.timestamp().to_string();
+    /// Switches between converting a date and date arithmetic.
+    pub fn toggle_mode(&mut self) {
+        self.mode = match self.mode {
+            DateMode::Convert => DateMode::Arithmetic,
+            DateMode::Arithmetic => DateMode::Convert,
+        };
+    }
+
+    /// Cycles the unit timestamp input is counted in.
+    pub fn cycle_precision(&mut self) {
+        self.precision = match self.precision {
+            TimestampPrecision::Auto => TimestampPrecision::Seconds,
+            TimestampPrecision::Seconds => TimestampPrecision::Milliseconds,
+            TimestampPrecision::Milliseconds => TimestampPrecision::Microseconds,
+            TimestampPrecision::Microseconds => TimestampPrecision::Nanoseconds,
+            TimestampPrecision::Nanoseconds => TimestampPrecision::Auto,
+        };
     }
 
     /// Converts the given datetime to a human-readable format.
-    fn convert_to_humanreadable(&mut self, datetime: DateTime<Utc>) {
-        self.human_readable = datetime.format("%A, %B %d, %Y, %I:%M:%S %p").to_string();
+    fn convert_to_humanreadable(&mut self, datetime: DateTime<FixedOffset>) {
+        self.human_readable = datetime.format(HUMAN_READABLE_FORMAT).to_string();
     }
 
     /// Converts the given datetime to a short date format.
-    fn convert_to_shortdate(&mut self, datetime: DateTime<Utc>) {
-        self.short_date = datetime.format("%d/%m/%Y").to_string();
+    fn convert_to_shortdate(&mut self, datetime: DateTime<FixedOffset>) {
+        self.short_date = match self.date_order {
+            DateOrder::DayFirst => datetime.format("%d/%m/%Y").to_string(),
+            DateOrder::MonthFirst => datetime.format("%m/%d/%Y").to_string(),
+        };
     }
 
     /// Converts the given datetime to a time-only format.
-    fn convert_to_timeonly(&mut self, datetime: DateTime<Utc>) {
-        self.time_only = datetime.format("%H:%M:%S").to_string();
+    fn convert_to_timeonly(&mut self, datetime: DateTime<FixedOffset>) {
+        self.time_only = datetime.format("%H:%M:%S%.f").to_string();
+    }
+
+    /// Converts the given datetime to each saved custom format, and the previewed one.
+    fn convert_to_custom_formats(&mut self, datetime: DateTime<FixedOffset>) {
+        self.datetime = Some(datetime);
+        self.custom_outputs = self
+            .custom_formats()
+            .into_iter()
+            .map(|(label, template)| {
+                let output = format_custom(datetime, &template);
+                (label, template, output)
+            })
+            .collect();
+    }
+
+    /// Returns the label and template of each saved custom format, followed by the previewed
+    /// one unless its template is already saved.
+    fn custom_formats(&self) -> Vec<(String, String)> {
+        let mut formats: Vec<(String, String)> = self
+            .saved_formats
+            .iter()
+            .map(|format| (format.name.clone(), format.template.clone()))
+            .collect();
+        if let Some(format) = &self.format_template {
+            if !self
+                .saved_formats
+                .iter()
+                .any(|saved| saved.template == format.template)
+            {
+                formats.push((
+                    format!("{} (Preview)", format.name),
+                    format.template.clone(),
+                ));
+            }
+        }
+        formats
+    }
+
+    /// Saves the custom format of the `FORMAT=` line, replacing a saved format of the same name.
+    /// Unnamed formats are numbered, e.g. `Custom Format 2`.
+    pub fn save_format(&mut self) {
+        let Some(mut format) = self.format_template.clone() else {
+            return;
+        };
+        if let Some(saved) = self
+            .saved_formats
+            .iter_mut()
+            .find(|saved| saved.name == format.name)
+        {
+            saved.template = format.template;
+            return;
+        }
+
+        // A template already saved under another name is not saved twice.
+        if self
+            .saved_formats
+            .iter()
+            .any(|saved| saved.template == format.template)
+        {
+            return;
+        }
+        if format.name == UNNAMED_FORMAT {
+            format.name = format!("{} {}", UNNAMED_FORMAT, self.saved_formats.len() + 1);
+        }
+        self.saved_formats.push(format);
+    }
+
+    /// Removes the last saved custom format.
+    pub fn remove_format(&mut self) {
+        self.saved_formats.pop();
+    }
+
+    /// Shows or hides the reference of strftime specifiers.
+    pub fn toggle_format_reference(&mut self) {
+        self.show_format_reference = !self.show_format_reference;
+    }
+
+    /// Returns each strftime specifier of the reference with its description, and the last
+    /// date converted in it.
+    pub fn format_reference(&self) -> Vec<(&'static str, &'static str, String)> {
+        FORMAT_SPECIFIERS
+            .iter()
+            .map(|(specifier, description)| {
+                let example = self
+                    .datetime
+                    .map(|datetime| format_custom(datetime, specifier))
+                    .unwrap_or_default();
+                (*specifier, *description, example)
+            })
+            .collect()
+    }
+
+    /// Shows or hides the calendar view.
+    pub fn toggle_calendar(&mut self) {
+        self.show_calendar = !self.show_calendar;
+    }
+
+    /// Shows or hides the world clock.
+    pub fn toggle_clock(&mut self) {
+        self.show_clock = !self.show_clock;
+    }
+
+    /// Returns how long to wait for input before the world clock shows the next second, if it is shown.
+    pub fn clock_tick(&self) -> Option<std::time::Duration> {
+        if !self.show_clock {
+            return None;
+        }
+        let elapsed = Utc::now().timestamp_subsec_nanos().min(999_999_999) as u64;
+        Some(std::time::Duration::from_nanos(1_000_000_000 - elapsed))
+    }
+
+    /// Returns the current Unix time and the current time in each of the display zones.
+    pub fn world_clock(&self, now: DateTime<Utc>) -> Vec<(String, String)> {
+        // Before any input, or with an invalid `ZONES=` line, the default zones are shown.
+        let zones = if self.display_zones.is_empty() {
+            [Zone::Local, Zone::Utc]
+                .into_iter()
+                .chain(DEFAULT_DISPLAY_ZONES.iter().map(|tz| Zone::Named(*tz)))
+                .collect()
+        } else {
+            self.display_zones.clone()
+        };
+
+        let mut clock = vec![
+            ("Unix Seconds".to_string(), now.timestamp().to_string()),
+            (
+                "Unix Milliseconds".to_string(),
+                now.timestamp_millis().to_string(),
+            ),
+        ];
+        clock.extend(zones.into_iter().map(|zone| {
+            (
+                zone_name(zone),
+                zone.convert(now).format("%H:%M:%S  %a, %d %b").to_string(),
+            )
+        }));
+        clock
+    }
+
+    /// Whether the arrow keys move the date from the calendar, which they only do while the
+    /// calendar is shown in the convert mode.
+    pub fn calendar_moves_date(&self) -> bool {
+        self.show_calendar && self.mode == DateMode::Convert
+    }
+
+    /// Returns the month of the last date converted, for the calendar view.
+    pub fn calendar(&self) -> Option<CalendarMonth> {
+        let datetime = self.datetime?;
+        let selected = datetime.date_naive();
+        let first = selected.with_day(1)?;
+        let last = first
+            .checked_add_months(Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .unwrap_or(NaiveDate::MAX);
+
+        // Weeks start on the Monday on or before the first day of the month.
+        let mut monday = first - Duration::days(first.weekday().num_days_from_monday() as i64);
+        let mut weeks = Vec::new();
+        while monday <= last {
+            let mut days = [None; 7];
+            for (offset, day) in days.iter_mut().enumerate() {
+                let date = monday + Duration::days(offset as i64);
+                if date.month() == selected.month() {
+                    *day = Some(date);
+                }
+            }
+            weeks.push((monday.iso_week().week(), days));
+            monday += Duration::days(7);
+        }
+
+        let days_in_year = if NaiveDate::from_ymd_opt(selected.year(), 2, 29).is_some() {
+            366
+        } else {
+            365
+        };
+        let iso_week = selected.iso_week();
+        Some(CalendarMonth {
+            title: selected.format("%B %Y").to_string(),
+            summary: vec![
+                format!("ISO Week {} of {}", iso_week.week(), iso_week.year()),
+                format!(
+                    "Day {} of {}, Q{}",
+                    selected.ordinal(),
+                    days_in_year,
+                    selected.month0() / 3 + 1
+                ),
+            ],
+            selected,
+            today: Utc::now().with_timezone(datetime.offset()).date_naive(),
+            weeks,
+        })
+    }
+
+    /// Moves the date by a number of days and months from the calendar view, keeping its time
+    /// and zone, and converts it again. The date lines of the input are replaced by the new
+    /// date. Returns whether the input changed; dates can only be moved when converting.
+    pub fn move_calendar(&mut self, days: i64, months: i32) -> bool {
+        let (Some(datetime), Some(zone)) = (self.datetime, self.entered_zone) else {
+            return false;
+        };
+        if self.mode != DateMode::Convert {
+            return false;
+        }
+
+        let naive = datetime.naive_local();
+        let moved = if months < 0 {
+            naive.checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            naive.checked_add_months(Months::new(months as u32))
+        }
+        .and_then(|naive| naive.checked_add_signed(Duration::try_days(days)?));
+        let Some(moved) = moved.filter(|moved| check_year(moved.year()).is_ok()) else {
+            return false;
+        };
+
+        // Keep the time only when the date has one, and the zone it was entered in.
+        let mut date = if moved.time() == NaiveTime::MIN {
+            moved.format("%Y-%m-%d").to_string()
+        } else {
+            moved.format("%Y-%m-%d %H:%M:%S%.f").to_string()
+        };
+        match zone {
+            Zone::Utc => {}
+            Zone::Local => date.push_str(" Local"),
+            Zone::Fixed(offset) => date.push_str(&format!(" {}", offset)),
+            Zone::Named(tz) => date.push_str(&format!(" {}", tz.name())),
+        }
+
+        // Keep the `ZONES=` and `FORMAT=` lines, in place of the date lines.
+        let mut lines = Vec::new();
+        for line in self.input.lines() {
+            let is_directive = line.trim().starts_with("ZONES=")
+                || line.trim().starts_with("zones=")
+                || parse_format_line(line.trim()).is_some();
+            if is_directive {
+                lines.push(line.to_string());
+            } else if !date.is_empty() {
+                lines.push(std::mem::take(&mut date));
+            }
+        }
+        if !date.is_empty() {
+            lines.insert(0, date);
+        }
+
+        self.input = lines.join("\n");
+        self.convert_all();
+        true
+    }
+
+    /// Exports the input and all converted formats to a text file.
+    pub fn write_to_file(&self) -> std::io::Result<()> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new("export/date.txt");
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // Write the input, the detected format and every conversion to the file.
+        writeln!(file, "Input: {}", self.input)?;
+        writeln!(file, "Mode: {}", self.mode.label())?;
+        writeln!(file, "Detected Format: {}", self.detected_format)?;
+        writeln!(file, "Entered In: {}", self.input_zone)?;
+        writeln!(file, "\nRFC 3339: {}", self.rfc3339)?;
+        writeln!(file, "RFC 2822: {}", self.rfc2822)?;
+        writeln!(file, "ISO 8601: {}", self.iso8601)?;
+        writeln!(file, "Unix Timestamp (Seconds): {}", self.unix_timestamp)?;
+        writeln!(
+            file,
+            "Unix Timestamp (Milliseconds): {}",
+            self.unix_milliseconds
+        )?;
+        writeln!(
+            file,
+            "Unix Timestamp (Microseconds): {}",
+            self.unix_microseconds
+        )?;
+        writeln!(
+            file,
+            "Unix Timestamp (Nanoseconds): {}",
+            self.unix_nanoseconds
+        )?;
+        writeln!(file, "Human Readable: {}", self.human_readable)?;
+        writeln!(file, "Relative: {}", self.relative)?;
+        writeln!(file, "Short Date: {}", self.short_date)?;
+        writeln!(file, "Time Only: {}", self.time_only)?;
+        writeln!(file, "\nTime Zones:")?;
+        writeln!(file, "{}", self.zone_times)?;
+
+        if !self.custom_outputs.is_empty() {
+            writeln!(file, "\nCustom Formats:")?;
+            for (label, template, output) in &self.custom_outputs {
+                writeln!(file, "{} ({}): {}", label, template, output)?;
+            }
+        }
+
+        if self.mode == DateMode::Arithmetic {
+            writeln!(file, "\nDate Arithmetic:")?;
+            match &self.arithmetic {
+                Ok(results) => {
+                    for (label, value) in results {
+                        writeln!(file, "{}: {}", label, value)?;
+                    }
+                }
+                Err(err) => writeln!(file, "{}", err)?,
+            }
+        }
+
+        // Return Ok(()) to indicate success.
+        Ok(())
+    }
+
+    /// Exports the input and all converted formats to a JSON file, for use in scripts.
+    /// Timestamps are written as numbers, and every other value as a string.
+    pub fn export_json(&self) -> std::io::Result<()> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new("export/date.json");
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // The date in each display zone, when the input could be converted.
+        let zones = match self.datetime {
+            Some(datetime) => {
+                let utc = datetime.with_timezone(&Utc);
+                let zones = self
+                    .display_zones
+                    .iter()
+                    .map(|zone| {
+                        json_object(
+                            &[
+                                ("zone", json_string(&zone_name(*zone))),
+                                ("date", json_string(&zone.convert(utc).to_rfc3339())),
+                                ("offset", json_string(&describe_offset(*zone, utc))),
+                            ],
+                            2,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                json_array(&zones, 1)
+            }
+            None => "[]".to_string(),
+        };
+        let custom_formats = self
+            .custom_outputs
+            .iter()
+            .map(|(label, template, output)| {
+                json_object(
+                    &[
+                        ("name", json_string(label)),
+                        ("template", json_string(template)),
+                        ("output", json_string(output)),
+                    ],
+                    2,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut fields = vec![
+            ("input", json_string(&self.input)),
+            ("mode", json_string(self.mode.label())),
+            ("detected_format", json_string(&self.detected_format)),
+            ("entered_in", json_string(&self.input_zone)),
+            ("rfc3339", json_string(&self.rfc3339)),
+            ("rfc2822", json_string(&self.rfc2822)),
+            ("iso8601", json_string(&self.iso8601)),
+            (
+                "unix_timestamp",
+                json_object(
+                    &[
+                        ("seconds", json_number(&self.unix_timestamp)),
+                        ("milliseconds", json_number(&self.unix_milliseconds)),
+                        ("microseconds", json_number(&self.unix_microseconds)),
+                        ("nanoseconds", json_number(&self.unix_nanoseconds)),
+                    ],
+                    1,
+                ),
+            ),
+            ("human_readable", json_string(&self.human_readable)),
+            ("relative", json_string(&self.relative)),
+            ("short_date", json_string(&self.short_date)),
+            ("time_only", json_string(&self.time_only)),
+            ("time_zones", zones),
+            ("custom_formats", json_array(&custom_formats, 1)),
+        ];
+        if self.mode == DateMode::Arithmetic {
+            let arithmetic = match &self.arithmetic {
+                Ok(results) => json_object(
+                    &results
+                        .iter()
+                        .map(|(label, value)| (*label, json_string(value)))
+                        .collect::<Vec<_>>(),
+                    1,
+                ),
+                Err(err) => json_string(err),
+            };
+            fields.push(("arithmetic", arithmetic));
+        }
+
+        // Write the JSON object to the file.
+        writeln!(file, "{}", json_object(&fields, 0))?;
+
+        // Return Ok(()) to indicate success.
+        Ok(())
     }
 
     /// Sets all output fields to the provided error message.
@@ -159,8 +1142,769 @@ impl DateConverter {
         self.rfc2822 = message.to_string();
         self.iso8601 = message.to_string();
         self.unix_timestamp = message.to_string();
+        self.unix_milliseconds = message.to_string();
+        self.unix_microseconds = message.to_string();
+        self.unix_nanoseconds = message.to_string();
         self.human_readable = message.to_string();
+        self.relative = message.to_string();
         self.short_date = message.to_string();
         self.time_only = message.to_string();
+        self.input_zone = message.to_string();
+        self.detected_format = message.to_string();
+        self.zone_times = message.to_string();
+        self.datetime = None;
+        self.entered_zone = None;
+        self.custom_outputs = self
+            .custom_formats()
+            .into_iter()
+            .map(|(label, template)| (label, template, message.to_string()))
+            .collect();
+    }
+}
+
+/// Formats a value as a JSON string, escaping quotes, backslashes and control characters.
+fn json_string(value: &str) -> String {
+    let mut json = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Formats a timestamp as a JSON number, or as a string when it holds an error message.
+fn json_number(value: &str) -> String {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, "0"));
+    let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+    if is_digits(whole) && is_digits(fraction) {
+        value.to_string()
+    } else {
+        json_string(value)
+    }
+}
+
+/// Formats keys and JSON values as a JSON object, indented by `depth` levels of two spaces.
+fn json_object(fields: &[(&str, String)], depth: usize) -> String {
+    if fields.is_empty() {
+        return "{}".to_string();
+    }
+
+    let indent = "  ".repeat(depth);
+    let fields = fields
+        .iter()
+        .map(|(key, value)| format!("{}  {}: {}", indent, json_string(key), value))
+        .collect::<Vec<_>>();
+    format!("{{\n{}\n{}}}", fields.join(",\n"), indent)
+}
+
+/// Formats JSON values as a JSON array, indented by `depth` levels of two spaces.
+fn json_array(values: &[String], depth: usize) -> String {
+    if values.is_empty() {
+        return "[]".to_string();
+    }
+
+    let indent = "  ".repeat(depth);
+    let values = values
+        .iter()
+        .map(|value| format!("{}  {}", indent, value))
+        .collect::<Vec<_>>();
+    format!("[\n{}\n{}]", values.join(",\n"), indent)
+}
+
+/// Parses a `FORMAT=%d %b %Y` line, or a named `FORMAT Invoice=%d-%m-%Y` one.
+fn parse_format_line(line: &str) -> Option<CustomFormat> {
+    let rest = line
+        .strip_prefix("FORMAT")
+        .or_else(|| line.strip_prefix("format"))?;
+    let (name, template) = rest.split_once('=')?;
+    if !name.is_empty() && !name.starts_with(' ') {
+        return None;
+    }
+
+    let name = match name.trim() {
+        "" => UNNAMED_FORMAT,
+        name => name,
+    };
+    Some(CustomFormat {
+        name: name.to_string(),
+        template: template.to_string(),
+    })
+}
+
+/// Formats a date with a strftime template. Returns an error message for templates with an
+/// unknown specifier, which chrono cannot format.
+fn format_custom(datetime: DateTime<FixedOffset>, template: &str) -> String {
+    let items: Vec<Item> = StrftimeItems::new(template).collect();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return format!(
+            "Invalid format '{}', press Alt + h for the supported specifiers",
+            template
+        );
+    }
+    datetime.format_with_items(items.into_iter()).to_string()
+}
+
+/// Parses a Unix timestamp such as `1700000000.123` in the given precision, detecting it from
+/// the magnitude with `TimestampPrecision::Auto`. Returns `None` when the input is not a number.
+fn parse_timestamp(
+    input: &str,
+    precision: TimestampPrecision,
+) -> Option<Result<(DateTime<Utc>, TimestampPrecision), String>> {
+    let (negative, digits) = match input.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, input),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let is_digits = |value: &str| value.chars().all(|c| c.is_ascii_digit());
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+
+    Some(timestamp_from_parts(negative, whole, fraction, precision))
+}
+
+/// Converts the whole and fractional digits of a timestamp to a date.
+fn timestamp_from_parts(
+    negative: bool,
+    whole: &str,
+    fraction: &str,
+    precision: TimestampPrecision,
+) -> Result<(DateTime<Utc>, TimestampPrecision), String> {
+    let out_of_range = || "Timestamp out of supported range".to_string();
+    let whole: i128 = whole.parse().map_err(|_| out_of_range())?;
+    let precision = match precision {
+        TimestampPrecision::Auto => TimestampPrecision::detect(whole),
+        precision => precision,
+    };
+
+    // Keep the fraction down to the nanosecond, e.g. `.5` milliseconds are 500000 nanoseconds.
+    let unit = precision.nanoseconds();
+    let mut fraction_nanoseconds = 0;
+    let mut scale = unit;
+    for digit in fraction.chars().filter_map(|c| c.to_digit(10)) {
+        scale /= 10;
+        fraction_nanoseconds += digit as i128 * scale;
+    }
+    let mut nanoseconds = whole
+        .checked_mul(unit)
+        .and_then(|value| value.checked_add(fraction_nanoseconds))
+        .ok_or_else(out_of_range)?;
+    if negative {
+        nanoseconds = -nanoseconds;
+    }
+
+    let seconds =
+        i64::try_from(nanoseconds.div_euclid(1_000_000_000)).map_err(|_| out_of_range())?;
+    let datetime = Utc
+        .timestamp_opt(seconds, nanoseconds.rem_euclid(1_000_000_000) as u32)
+        .single()
+        .ok_or_else(out_of_range)?;
+    check_year(datetime.year())?;
+    Ok((datetime, precision))
+}
+
+/// Formats a number of nanoseconds in a larger unit, with the remainder as a fraction without
+/// trailing zeros, e.g. `1700000000.5` seconds.
+fn format_scaled(nanoseconds: i128, unit: i128) -> String {
+    let sign = if nanoseconds < 0 { "-" } else { "" };
+    let whole = nanoseconds.unsigned_abs() / unit as u128;
+    let remainder = nanoseconds.unsigned_abs() % unit as u128;
+    if remainder == 0 {
+        return format!("{}{}", sign, whole);
+    }
+
+    let width = unit.ilog10() as usize;
+    let fraction = format!("{:0width$}", remainder, width = width);
+    format!("{}{}.{}", sign, whole, fraction.trim_end_matches('0'))
+}
+
+/// Parses a date without an offset in one of the supported formats, optionally followed by a
+/// time, a time on its own for today or a relative date, from `now` on the wall clock of the
+/// zone the date is read in. Returns the date along with the name of its format.
+fn parse_naive(
+    input: &str,
+    order: DateOrder,
+    now: NaiveDateTime,
+) -> Result<(NaiveDateTime, String), String> {
+    if let Some(relative) = parse_relative(input, now) {
+        return relative.map(|datetime| (datetime, "Relative Date".to_string()));
+    }
+
+    let input = &expand_hour_only(input);
+
+    // Try the slash separated formats in the preferred order.
+    let slash_formats = match order {
+        DateOrder::DayFirst => [("%d/%m/%Y", "DD/MM/YYYY"), ("%m/%d/%Y", "MM/DD/YYYY")],
+        DateOrder::MonthFirst => [("%m/%d/%Y", "MM/DD/YYYY"), ("%d/%m/%Y", "DD/MM/YYYY")],
+    };
+
+    for (date_format, label) in DATE_FORMATS.iter().chain(slash_formats.iter()) {
+        if let Ok(date) = NaiveDate::parse_from_str(input, date_format) {
+            check_year(date.year())?;
+            return Ok((date.and_hms_opt(0, 0, 0).unwrap(), label.to_string()));
+        }
+
+        // The time may follow a space, a `T` or a comma.
+        for separator in [" ", "T", ", "] {
+            for time_format in TIME_FORMATS {
+                let format = format!("{}{}{}", date_format, separator, time_format);
+                if let Ok(naive_datetime) = NaiveDateTime::parse_from_str(input, &format) {
+                    // Check if the year is within the supported range (1-9999).
+                    check_year(naive_datetime.year())?;
+                    return Ok((naive_datetime, label.to_string()));
+                }
+            }
+        }
+    }
+
+    // A time on its own is read as today.
+    for time_format in TIME_FORMATS {
+        if let Ok(time) = NaiveTime::parse_from_str(input, time_format) {
+            return Ok((now.date().and_time(time), "Time Only (Today)".to_string()));
+        }
+    }
+
+    // If all parsing attempts fail, return an error message indicating an unrecognized format.
+    Err("Unrecognized date-time format".to_string())
+}
+
+/// Adds the minutes to a trailing hour on its own, such as `9 am` or `2024-03-22 9PM`, which
+/// chrono cannot read without them.
+fn expand_hour_only(input: &str) -> String {
+    let lowercase = input.to_ascii_lowercase();
+    let Some(rest) = lowercase
+        .strip_suffix("am")
+        .or_else(|| lowercase.strip_suffix("pm"))
+    else {
+        return input.to_string();
+    };
+
+    // The hour must be one or two digits, on its own or after a space.
+    let hour_end = rest.trim_end().len();
+    let hour_start = rest[..hour_end]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !c.is_ascii_digit())
+        .map_or(0, |(index, c)| index + c.len_utf8());
+    if !(1..=2).contains(&(hour_end - hour_start))
+        || !(hour_start == 0 || rest[..hour_start].ends_with(' '))
+    {
+        return input.to_string();
+    }
+    format!("{}:00{}", &input[..hour_end], &input[hour_end..])
+}
+
+/// Parses relative dates such as `now`, `yesterday 14:00`, `next friday`, `in 3 days`,
+/// `2 hours ago` or `now + 90m`. Returns `None` when the input is not a relative date.
+fn parse_relative(input: &str, now: NaiveDateTime) -> Option<Result<NaiveDateTime, String>> {
+    let input = input.trim().to_lowercase();
+    let invalid = || {
+        format!(
+            "Unrecognized relative date '{}', try 'in 3 days', '2 hours ago' or 'now + 90m'",
+            input
+        )
+    };
+
+    // `in 3 days` and `2 hours ago` count from now.
+    let from_now = input
+        .strip_prefix("in ")
+        .map(|amount| (amount, 1))
+        .or_else(|| input.strip_suffix(" ago").map(|amount| (amount, -1)));
+    if let Some((amount, direction)) = from_now {
+        return Some(match parse_shift(amount) {
+            Some(shift) => shift_date(now, &shift, direction),
+            None => Err(invalid()),
+        });
+    }
+
+    // Otherwise a day, optionally followed by a time and `+ 1h` or `- 2 days` shifts.
+    let shifts_start = input.find(['+', '-']).unwrap_or(input.len());
+    let (base, shifts) = input.split_at(shifts_start);
+    let mut words = base.split_whitespace();
+    let today = now.date();
+    let (mut datetime, has_time) = match words.next()? {
+        "now" => (now, true),
+        "today" => (today.and_time(NaiveTime::MIN), false),
+        "yesterday" => (today.pred_opt()?.and_time(NaiveTime::MIN), false),
+        "tomorrow" => (today.succ_opt()?.and_time(NaiveTime::MIN), false),
+        // `next friday` is the first friday after today, `last friday` the last one before.
+        direction @ ("next" | "last") => {
+            let weekday = match words.next().map(str::parse::<Weekday>) {
+                Some(Ok(weekday)) => weekday,
+                _ => return Some(Err(invalid())),
+            };
+            let mut date = today;
+            loop {
+                date = if direction == "next" {
+                    date.succ_opt()?
+                } else {
+                    date.pred_opt()?
+                };
+                if date.weekday() == weekday {
+                    break;
+                }
+            }
+            (date.and_time(NaiveTime::MIN), false)
+        }
+        _ => return None,
+    };
+
+    // A time may follow the day, e.g. `yesterday 14:00` or `tomorrow at 9 am`.
+    let time: Vec<&str> = words.filter(|word| *word != "at").collect();
+    if !time.is_empty() {
+        let time = expand_hour_only(&time.join(" "));
+        match TIME_FORMATS
+            .iter()
+            .find_map(|format| NaiveTime::parse_from_str(&time, format).ok())
+        {
+            Some(time) if !has_time => datetime = datetime.date().and_time(time),
+            _ => return Some(Err(invalid())),
+        }
+    }
+
+    // Apply every `+` and `-` shift in turn.
+    let mut rest = shifts.trim();
+    while let Some(sign) = rest.chars().next() {
+        let amount_end = rest[1..].find(['+', '-']).map_or(rest.len(), |end| end + 1);
+        let shift = match parse_shift(&rest[1..amount_end]) {
+            Some(shift) => shift,
+            None => return Some(Err(invalid())),
+        };
+        datetime = match shift_date(datetime, &shift, if sign == '-' { -1 } else { 1 }) {
+            Ok(datetime) => datetime,
+            Err(err) => return Some(Err(err)),
+        };
+        rest = rest[amount_end..].trim();
+    }
+
+    Some(check_year(datetime.year()).map(|_| datetime))
+}
+
+/// Shifts a date forward, or backward with a negative `direction`, checking that the result
+/// stays within the supported years.
+fn shift_date(
+    datetime: NaiveDateTime,
+    shift: &Shift,
+    direction: i32,
+) -> Result<NaiveDateTime, String> {
+    shift
+        .apply(datetime, direction)
+        .filter(|datetime| check_year(datetime.year()).is_ok())
+        .ok_or_else(|| "Year out of supported range (1-9999)".to_string())
+}
+
+/// An amount of time to shift a date by. Months and days are counted on the calendar, while
+/// hours, minutes and seconds are an exact duration.
+struct Shift {
+    months: u32,
+    days: u64,
+    duration: Duration,
+}
+
+impl Shift {
+    /// Adds the months and days of a value such as `1y 2mo 3d` or `P3D`.
+    fn add_calendar(&mut self, months: i64, days: i64) -> Option<()> {
+        self.months = self.months.checked_add(u32::try_from(months).ok()?)?;
+        self.days = self.days.checked_add(u64::try_from(days).ok()?)?;
+        Some(())
+    }
+
+    /// Shifts a date by the months and days of the shift, forward or backward with a negative
+    /// `direction`. Returns `None` when the result does not fit in a date.
+    fn apply_calendar(&self, datetime: NaiveDateTime, direction: i32) -> Option<NaiveDateTime> {
+        let (months, days) = (Months::new(self.months), Days::new(self.days));
+        if direction < 0 {
+            datetime.checked_sub_months(months)?.checked_sub_days(days)
+        } else {
+            datetime.checked_add_months(months)?.checked_add_days(days)
+        }
+    }
+
+    /// Shifts a date forward, or backward with a negative `direction`. Returns `None` when the
+    /// result does not fit in a date.
+    fn apply(&self, datetime: NaiveDateTime, direction: i32) -> Option<NaiveDateTime> {
+        let datetime = self.apply_calendar(datetime, direction)?;
+        if direction < 0 {
+            datetime.checked_sub_signed(self.duration)
+        } else {
+            datetime.checked_add_signed(self.duration)
+        }
+    }
+}
+
+/// Parses an amount of time such as `3 days`, `90m` or `1h 30m`.
+fn parse_shift(amount: &str) -> Option<Shift> {
+    let mut shift = Shift {
+        months: 0,
+        days: 0,
+        duration: Duration::zero(),
+    };
+    let mut rest = amount.trim();
+    if rest.is_empty() {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let number: i64 = rest[..number_end].parse().ok()?;
+        rest = rest[number_end..].trim_start();
+        let unit_end = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        match &rest[..unit_end] {
+            "s" | "sec" | "secs" | "second" | "seconds" => {
+                shift.duration += Duration::try_seconds(number)?
+            }
+            "m" | "min" | "mins" | "minute" | "minutes" => {
+                shift.duration += Duration::try_minutes(number)?
+            }
+            "h" | "hr" | "hrs" | "hour" | "hours" => shift.duration += Duration::try_hours(number)?,
+            "d" | "day" | "days" => shift.add_calendar(0, number)?,
+            "w" | "week" | "weeks" => shift.add_calendar(0, number.checked_mul(7)?)?,
+            "mo" | "month" | "months" => shift.add_calendar(number, 0)?,
+            "y" | "year" | "years" => shift.add_calendar(number.checked_mul(12)?, 0)?,
+            _ => return None,
+        }
+        rest = rest[unit_end..].trim_start();
+    }
+
+    Some(shift)
+}
+
+/// Parses a duration to add to a date: an ISO 8601 duration such as `P1Y2M3DT4H` or an amount
+/// such as `45 days`, optionally preceded by `+` or `-`. Returns the direction, `-1` to
+/// subtract, along with the duration, or `None` when the input is not a duration.
+fn parse_duration(input: &str) -> Option<(i32, Shift)> {
+    let input = input.trim();
+    let (direction, amount) = match input.strip_prefix('-') {
+        Some(amount) => (-1, amount),
+        None => (1, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let amount = amount.trim();
+    parse_iso_duration(amount)
+        .or_else(|| parse_shift(&amount.to_lowercase()))
+        .map(|shift| (direction, shift))
+}
+
+/// Parses an ISO 8601 duration such as `P1Y2M3DT4H5M6.5S` or `P2W`.
+fn parse_iso_duration(input: &str) -> Option<Shift> {
+    let rest = input.strip_prefix(['P', 'p'])?;
+    let (date, time) = match rest.split_once(['T', 't']) {
+        Some((date, time)) if !time.is_empty() => (date, time),
+        Some(_) => return None,
+        None => (rest, ""),
+    };
+    if date.is_empty() && time.is_empty() {
+        return None;
+    }
+
+    let mut shift = Shift {
+        months: 0,
+        days: 0,
+        duration: Duration::zero(),
+    };
+    for (number, designator) in duration_parts(date)? {
+        let number: i64 = number.parse().ok()?;
+        match designator.to_ascii_uppercase() {
+            'Y' => shift.add_calendar(number.checked_mul(12)?, 0)?,
+            'M' => shift.add_calendar(number, 0)?,
+            'W' => shift.add_calendar(0, number.checked_mul(7)?)?,
+            'D' => shift.add_calendar(0, number)?,
+            _ => return None,
+        }
+    }
+    for (number, designator) in duration_parts(time)? {
+        match designator.to_ascii_uppercase() {
+            'H' => shift.duration += Duration::try_hours(number.parse().ok()?)?,
+            'M' => shift.duration += Duration::try_minutes(number.parse().ok()?)?,
+            // Seconds may have a fraction, e.g. `6.5S` or `6,5S`.
+            'S' => {
+                let (whole, fraction) = number.split_once(['.', ',']).unwrap_or((number, ""));
+                if fraction.len() > 9 {
+                    return None;
+                }
+                let nanoseconds = if fraction.is_empty() {
+                    0
+                } else {
+                    format!("{:0<9}", fraction).parse().ok()?
+                };
+                shift.duration += Duration::try_seconds(whole.parse().ok()?)?;
+                shift.duration += Duration::nanoseconds(nanoseconds);
+            }
+            _ => return None,
+        }
+    }
+
+    Some(shift)
+}
+
+/// Splits the date or time part of an ISO 8601 duration into numbers and their designators,
+/// e.g. `1Y2M` into `[("1", 'Y'), ("2", 'M')]`.
+fn duration_parts(part: &str) -> Option<Vec<(&str, char)>> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for (index, c) in part.char_indices() {
+        if c.is_ascii_alphabetic() {
+            let number = &part[start..index];
+            if number.is_empty() || !number.starts_with(|c: char| c.is_ascii_digit()) {
+                return None;
+            }
+            parts.push((number, c));
+            start = index + 1;
+        } else if !c.is_ascii_digit() && c != '.' && c != ',' {
+            return None;
+        }
+    }
+
+    // Every number must be followed by its designator.
+    if start == part.len() {
+        Some(parts)
+    } else {
+        None
+    }
+}
+
+/// Counts the whole calendar months and days from `start` to `end`, along with the time left
+/// over. `start` must not be after `end`.
+fn calendar_difference(start: NaiveDateTime, end: NaiveDateTime) -> (u32, u64, Duration) {
+    let mut months = ((end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32)
+        .max(0) as u32;
+    while months > 0
+        && start
+            .checked_add_months(Months::new(months))
+            .is_none_or(|date| date > end)
+    {
+        months -= 1;
+    }
+    let anchor = start
+        .checked_add_months(Months::new(months))
+        .unwrap_or(start);
+    let rest = end - anchor;
+    let days = rest.num_days();
+    (months, days as u64, rest - Duration::days(days))
+}
+
+/// Splits a duration into hours, minutes, seconds and nanoseconds.
+fn duration_units(duration: Duration) -> (i64, i64, i64, i32) {
+    let seconds = duration.num_seconds();
+    (
+        seconds / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60,
+        duration.subsec_nanos(),
+    )
+}
+
+/// Formats a duration in ISO 8601, e.g. `P1Y2M3DT4H`, with a leading `-` when `negative`.
+fn format_iso_duration(months: u32, days: u64, time: Duration, negative: bool) -> String {
+    let (hours, minutes, seconds, nanoseconds) = duration_units(time);
+    let mut date = String::new();
+    for (value, designator) in [(months / 12, 'Y'), (months % 12, 'M')] {
+        if value > 0 {
+            date.push_str(&format!("{}{}", value, designator));
+        }
+    }
+    if days > 0 {
+        date.push_str(&format!("{}D", days));
+    }
+
+    let mut time = String::new();
+    for (value, designator) in [(hours, 'H'), (minutes, 'M')] {
+        if value > 0 {
+            time.push_str(&format!("{}{}", value, designator));
+        }
+    }
+    if seconds > 0 || nanoseconds > 0 {
+        let nanoseconds = seconds as i128 * 1_000_000_000 + nanoseconds as i128;
+        time.push_str(&format!("{}S", format_scaled(nanoseconds, 1_000_000_000)));
+    }
+
+    let sign = if negative { "-" } else { "" };
+    match (date.is_empty(), time.is_empty()) {
+        (true, true) => "PT0S".to_string(),
+        (_, true) => format!("{}P{}", sign, date),
+        _ => format!("{}P{}T{}", sign, date, time),
+    }
+}
+
+/// Describes a duration in words, e.g. `1 year, 2 months, 3 days and 4 hours`.
+fn describe_duration(months: u32, days: u64, time: Duration) -> String {
+    let (hours, minutes, seconds, nanoseconds) = duration_units(time);
+    let mut parts = Vec::new();
+    for (value, unit) in [
+        (months as i64 / 12, "year"),
+        (months as i64 % 12, "month"),
+        (days as i64, "day"),
+        (hours, "hour"),
+        (minutes, "minute"),
+    ] {
+        if value > 0 {
+            parts.push(format!(
+                "{} {}{}",
+                value,
+                unit,
+                if value == 1 { "" } else { "s" }
+            ));
+        }
+    }
+    if seconds > 0 || nanoseconds > 0 {
+        let nanoseconds = seconds as i128 * 1_000_000_000 + nanoseconds as i128;
+        let seconds = format_scaled(nanoseconds, 1_000_000_000);
+        let plural = if seconds == "1" { "" } else { "s" };
+        parts.push(format!("{} second{}", seconds, plural));
+    }
+
+    match parts.len() {
+        0 => "0 seconds".to_string(),
+        1 => parts.remove(0),
+        _ => {
+            let last = parts.pop().unwrap_or_default();
+            format!("{} and {}", parts.join(", "), last)
+        }
+    }
+}
+
+/// Formats a number of nanoseconds as a total in a unit of `seconds`, with up to 4 decimals.
+fn format_total(nanoseconds: i128, seconds: i128) -> String {
+    let unit = seconds * 1_000_000_000;
+    if nanoseconds % unit == 0 {
+        return (nanoseconds / unit).to_string();
+    }
+
+    let total = format!("{:.4}", nanoseconds as f64 / unit as f64);
+    total
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Counts the weekdays, Monday to Friday, from `start` up to but not including `end`. The count
+/// is negative when `end` is before `start`.
+fn business_days(start: NaiveDate, end: NaiveDate) -> i64 {
+    if end < start {
+        return -business_days(end, start);
+    }
+
+    let days = (end - start).num_days();
+    let first_weekday = start.weekday().num_days_from_monday() as i64;
+    let remaining = (0..days % 7)
+        .filter(|offset| (first_weekday + offset) % 7 < 5)
+        .count() as i64;
+    days / 7 * 5 + remaining
+}
+
+/// Describes how far a date is from now in its largest unit, e.g. `3 days ago` or `in 2 weeks`.
+fn describe_relative(difference: Duration) -> String {
+    let seconds = difference.num_milliseconds().unsigned_abs() as f64 / 1000.0;
+    if seconds < 1.0 {
+        return "just now".to_string();
+    }
+
+    let (value, unit) = match seconds {
+        s if s < 60.0 => (s, "second"),
+        s if s < 3_600.0 => (s / 60.0, "minute"),
+        s if s < 86_400.0 => (s / 3_600.0, "hour"),
+        s if s < 7.0 * 86_400.0 => (s / 86_400.0, "day"),
+        s if s < 30.0 * 86_400.0 => (s / (7.0 * 86_400.0), "week"),
+        s if s < 365.0 * 86_400.0 => (s / (30.44 * 86_400.0), "month"),
+        s => (s / (365.25 * 86_400.0), "year"),
+    };
+    let value = value.round() as u64;
+    let plural = if value == 1 { "" } else { "s" };
+    if difference > Duration::zero() {
+        format!("in {} {}{}", value, unit, plural)
+    } else {
+        format!("{} {}{} ago", value, unit, plural)
+    }
+}
+
+/// Checks that a year is within the supported range (1-9999).
+fn check_year(year: i32) -> Result<(), String> {
+    if (1..=9999).contains(&year) {
+        Ok(())
+    } else {
+        Err("Year out of supported range (1-9999)".to_string())
+    }
+}
+
+/// Parses the comma separated zones of a `ZONES=` line, e.g. `Europe/Paris, Asia/Tokyo`.
+fn parse_zone_list(zones: &str) -> Result<Vec<Zone>, String> {
+    zones
+        .split(',')
+        .map(str::trim)
+        .filter(|zone| !zone.is_empty())
+        .map(|zone| {
+            Zone::parse(zone).ok_or_else(|| {
+                format!(
+                    "Unknown time zone '{}': use an IANA name like Europe/Paris or an offset like +05:30",
+                    zone
+                )
+            })
+        })
+        .collect()
+}
+
+/// Returns the name of a zone, with the name of the local zone spelled out.
+fn zone_name(zone: Zone) -> String {
+    match zone {
+        Zone::Local => "Local Time".to_string(),
+        _ => zone.label(),
+    }
+}
+
+/// Describes the offset of a zone at an instant, e.g. `EDT, UTC-04:00, daylight saving time`.
+fn describe_offset(zone: Zone, datetime: DateTime<Utc>) -> String {
+    let offset = zone.convert(datetime).format("UTC%:z").to_string();
+    match zone.abbreviation(datetime) {
+        (abbreviation, true) => format!("{}, {}, daylight saving time", abbreviation, offset),
+        (abbreviation, false) if matches!(zone, Zone::Named(_)) => {
+            format!("{}, {}", abbreviation, offset)
+        }
+        _ => offset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_hour_only_skips_non_ascii_before_the_meridiem() {
+        assert_eq!(expand_hour_only("9 am"), "9:00 am");
+        assert_eq!(expand_hour_only("é am"), "é am");
+        assert_eq!(expand_hour_only("éam"), "éam");
+        assert_eq!(expand_hour_only("é9pm"), "é9pm");
+    }
+
+    #[test]
+    fn parse_relative_rejects_non_ascii_times() {
+        let now = NaiveDate::from_ymd_opt(2024, 3, 22)
+            .and_then(|date| date.and_hms_opt(12, 0, 0))
+            .expect("valid date");
+        assert!(matches!(
+            parse_relative("tomorrow at é am", now),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            parse_relative("tomorrow at éam", now),
+            Some(Err(_))
+        ));
+        assert_eq!(
+            parse_relative("tomorrow at 9 am", now),
+            NaiveDate::from_ymd_opt(2024, 3, 23)
+                .and_then(|date| date.and_hms_opt(9, 0, 0))
+                .map(Ok)
+        );
     }
 }
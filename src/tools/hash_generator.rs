@@ -1,23 +1,108 @@
+use blake2::Blake2b512;
 use digest::Digest;
+use md5::Md5;
 use sha1::Sha1;
-use sha2::{Sha256, Sha384, Sha512};
+use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512_256};
+use sha3::Sha3_256;
 
 use std::fs::{create_dir_all, File};
 use std::io::Write;
 use std::path::Path;
 
+/// A hash algorithm the generator can compute.
+#[derive(Clone, Copy, PartialEq)]
+pub enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_256,
+    Sha3_256,
+    Blake2b,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    /// Every algorithm, in the order they are listed, shown and toggled with Alt + 1-9 and Alt + 0.
+    pub const ALL: [HashAlgorithm; 10] = [
+        HashAlgorithm::Md5,
+        HashAlgorithm::Sha1,
+        HashAlgorithm::Sha224,
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha384,
+        HashAlgorithm::Sha512,
+        HashAlgorithm::Sha512_256,
+        HashAlgorithm::Sha3_256,
+        HashAlgorithm::Blake2b,
+        HashAlgorithm::Blake3,
+    ];
+
+    /// Returns the name of the algorithm.
+    pub fn label(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Md5 => "MD5",
+            HashAlgorithm::Sha1 => "SHA-1",
+            HashAlgorithm::Sha224 => "SHA-224",
+            HashAlgorithm::Sha256 => "SHA-256",
+            HashAlgorithm::Sha384 => "SHA-384",
+            HashAlgorithm::Sha512 => "SHA-512",
+            HashAlgorithm::Sha512_256 => "SHA-512/256",
+            HashAlgorithm::Sha3_256 => "SHA3-256",
+            HashAlgorithm::Blake2b => "BLAKE2b-512",
+            HashAlgorithm::Blake3 => "BLAKE3",
+        }
+    }
+
+    /// Returns the key that shows or hides the algorithm, along with Alt.
+    pub fn key(&self) -> char {
+        match self {
+            HashAlgorithm::Md5 => '1',
+            HashAlgorithm::Sha1 => '2',
+            HashAlgorithm::Sha224 => '3',
+            HashAlgorithm::Sha256 => '4',
+            HashAlgorithm::Sha384 => '5',
+            HashAlgorithm::Sha512 => '6',
+            HashAlgorithm::Sha512_256 => '7',
+            HashAlgorithm::Sha3_256 => '8',
+            HashAlgorithm::Blake2b => '9',
+            HashAlgorithm::Blake3 => '0',
+        }
+    }
+
+    /// Returns the algorithm shown or hidden by a key, along with Alt.
+    pub fn from_key(key: char) -> Option<HashAlgorithm> {
+        HashAlgorithm::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.key() == key)
+    }
+
+    /// Returns the hex representation of the hash of the given bytes.
+    pub fn hash(&self, input: &[u8]) -> String {
+        match self {
+            HashAlgorithm::Md5 => digest_hex::<Md5>(input),
+            HashAlgorithm::Sha1 => digest_hex::<Sha1>(input),
+            HashAlgorithm::Sha224 => digest_hex::<Sha224>(input),
+            HashAlgorithm::Sha256 => digest_hex::<Sha256>(input),
+            HashAlgorithm::Sha384 => digest_hex::<Sha384>(input),
+            HashAlgorithm::Sha512 => digest_hex::<Sha512>(input),
+            HashAlgorithm::Sha512_256 => digest_hex::<Sha512_256>(input),
+            HashAlgorithm::Sha3_256 => digest_hex::<Sha3_256>(input),
+            HashAlgorithm::Blake2b => digest_hex::<Blake2b512>(input),
+            HashAlgorithm::Blake3 => blake3::hash(input).to_hex().to_string(),
+        }
+    }
+}
+
 /// Struct to generate various hash values for an input string.
 pub struct HashGenerator {
     /// The input string for which the hashes will be generated.
     input: String,
-    /// The SHA-1 hash of the input string.
-    sha1_hash: String,
-    /// The SHA-256 hash of the input string.
-    sha256_hash: String,
-    /// The SHA-384 hash of the input string.
-    sha384_hash: String,
-    /// The SHA-512 hash of the input string.
-    sha512_hash: String,
+    /// The algorithms shown, in the order of `HashAlgorithm::ALL`.
+    pub shown: Vec<HashAlgorithm>,
+    /// The hash of the input string for each shown algorithm.
+    hashes: Vec<(HashAlgorithm, String)>,
     /// A potential message for tools export.
     pub tools_export_message: Option<String>,
 }
@@ -27,10 +112,13 @@ impl HashGenerator {
     pub fn new() -> Self {
         HashGenerator {
             input: String::new(),
-            sha1_hash: String::new(),
-            sha256_hash: String::new(),
-            sha384_hash: String::new(),
-            sha512_hash: String::new(),
+            shown: vec![
+                HashAlgorithm::Sha1,
+                HashAlgorithm::Sha256,
+                HashAlgorithm::Sha384,
+                HashAlgorithm::Sha512,
+            ],
+            hashes: Vec::new(),
             tools_export_message: None,
         }
     }
@@ -44,81 +132,37 @@ impl HashGenerator {
         self.calculate_hashes();
     }
 
-    /// Calculates all the hash values based on the current `input`.
+    /// Calculates the hash values of the shown algorithms based on the current `input`.
     fn calculate_hashes(&mut self) {
-        // Calculates and updates each hash.
-        self.sha1_hash = self.generate_sha1();
-        self.sha256_hash = self.generate_sha256();
-        self.sha384_hash = self.generate_sha384();
-        self.sha512_hash = self.generate_sha512();
-    }
-
-    /// Returns the SHA-1 hash of the input string.
-    pub fn get_sha1(&self) -> &str {
-        &self.sha1_hash
-    }
-
-    /// Returns the SHA-256 hash of the input string.
-    pub fn get_sha256(&self) -> &str {
-        &self.sha256_hash
-    }
-
-    /// Returns the SHA-384 hash of the input string.
-    pub fn get_sha384(&self) -> &str {
-        &self.sha384_hash
+        self.hashes = self
+            .shown
+            .iter()
+            .map(|algorithm| (*algorithm, algorithm.hash(self.input.as_bytes())))
+            .collect();
     }
 
-    /// Returns the SHA-512 hash of the input string.
-    pub fn get_sha512(&self) -> &str {
-        &self.sha512_hash
-    }
-
-    /// Generates the SHA-1 hash of the input string.
-    pub fn generate_sha1(&self) -> String {
-        // Create a new SHA-1 hasher.
-        let mut hasher = Sha1::new();
-
-        // Update the hasher with the input string bytes.
-        Digest::update(&mut hasher, self.input.as_bytes());
-
-        // Finalize the hash calculation and return the hex representation.
-        format!("{:x}", hasher.finalize())
-    }
-
-    /// Generates the SHA-256 hash of the input string.
-    pub fn generate_sha256(&self) -> String {
-        // Create a new SHA-256 hasher.
-        let mut hasher = Sha256::new();
-
-        // Update the hasher with the input string bytes.
-        Digest::update(&mut hasher, self.input.as_bytes());
-
-        // Finalize the hash calculation and return the hex representation.
-        format!("{:x}", hasher.finalize())
-    }
-
-    /// Generates the SHA-384 hash of the input string.
-    pub fn generate_sha384(&self) -> String {
-        // Create a new SHA-384 hasher.
-        let mut hasher = Sha384::new();
-
-        // Update the hasher with the input string bytes.
-        Digest::update(&mut hasher, self.input.as_bytes());
-
-        // Finalize the hash calculation and return the hex representation.
-        format!("{:x}", hasher.finalize())
+    /// Shows or hides an algorithm, keeping at least one shown.
+    pub fn toggle_algorithm(&mut self, algorithm: HashAlgorithm) {
+        if self.shown.contains(&algorithm) {
+            if self.shown.len() > 1 {
+                self.shown.retain(|shown| *shown != algorithm);
+            }
+        } else {
+            self.shown = HashAlgorithm::ALL
+                .into_iter()
+                .filter(|candidate| *candidate == algorithm || self.shown.contains(candidate))
+                .collect();
+        }
+        self.calculate_hashes();
     }
 
-    /// Generates the SHA-512 hash of the input string.
-    pub fn generate_sha512(&self) -> String {
-        // Create a new SHA-512 hasher.
-        let mut hasher = Sha512::new();
-
-        // Update the hasher with the input string bytes.
-        Digest::update(&mut hasher, self.input.as_bytes());
-
-        // Finalize the hash calculation and return the hex representation.
-        format!("{:x}", hasher.finalize())
+    /// Returns the hash of the input string for a shown algorithm.
+    pub fn get_hash(&self, algorithm: HashAlgorithm) -> &str {
+        self.hashes
+            .iter()
+            .find(|(hashed, _)| *hashed == algorithm)
+            .map(|(_, hash)| hash.as_str())
+            .unwrap_or("")
     }
 
     /// Exports the generated hashes to a file.
@@ -132,14 +176,25 @@ impl HashGenerator {
         // Open the file for writing.
         let mut file = File::create(file_path)?;
 
-        // Write the input and hash values to the file.
+        // Write the input and the hash values of the shown algorithms to the file.
         writeln!(file, "Input: {}", self.input)?;
-        writeln!(file, "SHA1: {}", self.sha1_hash)?;
-        writeln!(file, "SHA256: {}", self.sha256_hash)?;
-        writeln!(file, "SHA384: {}", self.sha384_hash)?;
-        writeln!(file, "SHA512: {}", self.sha512_hash)?;
+        for (algorithm, hash) in &self.hashes {
+            writeln!(file, "{}: {}", algorithm.label(), hash)?;
+        }
 
         // Return Ok(()) to indicate success.
         Ok(())
     }
 }
+
+/// Returns the hex representation of the hash of the given bytes with a `Digest` hasher.
+fn digest_hex<D: Digest>(input: &[u8]) -> String {
+    // Create a new hasher, update it with the input bytes and finalize the hash calculation.
+    let mut hasher = D::new();
+    Digest::update(&mut hasher, input);
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
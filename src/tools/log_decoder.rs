@@ -0,0 +1,406 @@
+use crate::tools::date_converter::HUMAN_READABLE_FORMAT;
+use crate::tools::time_zone::Zone;
+use chrono::{DateTime, Datelike, NaiveDateTime, Utc};
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::Path;
+
+/// Struct to detect timestamps in log lines and annotate them with a human-readable date.
+pub struct LogDecoder {
+    /// The pasted log lines.
+    pub input: String,
+    /// The time zone selected for the annotations.
+    pub time_zone: Zone,
+    /// The time zone the log was last annotated in (a leading `TZ=` line overrides the selection).
+    pub active_time_zone: Zone,
+    /// The log with every detected timestamp followed by its human-readable form.
+    pub annotated_log: String,
+    /// The number of timestamps detected, by kind.
+    pub summary: String,
+    /// A potential message for tools export.
+    pub tools_export_message: Option<String>,
+}
+
+impl LogDecoder {
+    /// Creates a new instance of `LogDecoder`.
+    pub fn new() -> Self {
+        LogDecoder {
+            input: String::new(),
+            time_zone: Zone::Utc,
+            active_time_zone: Zone::Utc,
+            annotated_log: String::new(),
+            summary: String::new(),
+            tools_export_message: None,
+        }
+    }
+
+    /// Switches the annotations between UTC and local time.
+    pub fn toggle_time_zone(&mut self) {
+        self.time_zone = match self.time_zone {
+            Zone::Utc => Zone::Local,
+            _ => Zone::Utc,
+        };
+    }
+
+    /// Detects the timestamps in the `input` and annotates each of them.
+    ///
+    /// Epoch timestamps are recognized by their number of digits: 10 for seconds (optionally
+    /// with a fraction), 13 for milliseconds, 16 for microseconds and 19 for nanoseconds.
+    /// ISO 8601 timestamps such as `2024-03-22T10:00:00.123Z` are recognized as well.
+    pub fn decode(&mut self) {
+        self.active_time_zone = self.time_zone;
+        let mut lines: Vec<&str> = self.input.lines().collect();
+
+        // A leading `TZ=` line selects the time zone for the whole log.
+        if let Some(value) = lines.first().and_then(|line| {
+            line.trim()
+                .strip_prefix("TZ=")
+                .or_else(|| line.trim().strip_prefix("tz="))
+        }) {
+            match Zone::parse(value.trim()) {
+                Some(time_zone) => self.active_time_zone = time_zone,
+                None => {
+                    let message = format!(
+                        "Invalid time zone '{}': use UTC, Local, an offset like +05:30 or a zone like Europe/Paris",
+                        value.trim()
+                    );
+                    self.annotated_log = message.clone();
+                    self.summary = message;
+                    return;
+                }
+            }
+            lines.remove(0);
+        }
+
+        let mut counts = [0; 5];
+        let annotated: Vec<String> = lines
+            .iter()
+            .map(|line| annotate_line(line, self.active_time_zone, &mut counts))
+            .collect();
+        self.annotated_log = annotated.join("\n");
+
+        let total: usize = counts.iter().sum();
+        let mut summary = vec![format!("Timestamps Found: {}", total)];
+        for (kind, count) in TimestampKind::ALL.iter().zip(counts) {
+            if count > 0 {
+                summary.push(format!("{}: {}", kind.label(), count));
+            }
+        }
+        summary.push(format!("Time Zone: {}", self.active_time_zone.label()));
+        self.summary = summary.join("\n");
+    }
+
+    /// Exports the summary and the annotated log to a file.
+    pub fn write_to_file(&self) -> std::io::Result<()> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new("export/log.txt");
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // Write the summary and the annotated log to the file.
+        writeln!(file, "{}", self.summary)?;
+        writeln!(file, "\n{}", self.annotated_log)?;
+
+        // Return Ok(()) to indicate success.
+        Ok(())
+    }
+}
+
+/// The kinds of timestamps that can be detected.
+#[derive(Clone, Copy)]
+enum TimestampKind {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+    Iso8601,
+}
+
+impl TimestampKind {
+    /// All kinds, in the order they are counted and listed in the summary.
+    const ALL: [TimestampKind; 5] = [
+        TimestampKind::Seconds,
+        TimestampKind::Milliseconds,
+        TimestampKind::Microseconds,
+        TimestampKind::Nanoseconds,
+        TimestampKind::Iso8601,
+    ];
+
+    /// Returns the name of the kind, as shown in the summary.
+    fn label(&self) -> &'static str {
+        match self {
+            TimestampKind::Seconds => "Epoch Seconds",
+            TimestampKind::Milliseconds => "Epoch Milliseconds",
+            TimestampKind::Microseconds => "Epoch Microseconds",
+            TimestampKind::Nanoseconds => "Epoch Nanoseconds",
+            TimestampKind::Iso8601 => "ISO 8601",
+        }
+    }
+}
+
+/// A timestamp found in a line, with the byte offset it ends at.
+struct Timestamp {
+    end: usize,
+    datetime: DateTime<Utc>,
+    /// The number of fractional second digits to show.
+    precision: usize,
+    kind: TimestampKind,
+}
+
+/// Returns the line with a human-readable annotation after every timestamp, counting them by kind.
+fn annotate_line(line: &str, time_zone: Zone, counts: &mut [usize; 5]) -> String {
+    let bytes = line.as_bytes();
+    let mut annotated = String::with_capacity(line.len());
+    let mut copied = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        // Timestamps start with a digit that is not part of a longer word or number.
+        if !bytes[i].is_ascii_digit() || (i > 0 && is_word_byte(bytes[i - 1])) {
+            i += 1;
+            continue;
+        }
+
+        match parse_iso8601(bytes, i).or_else(|| parse_epoch(bytes, i)) {
+            Some(timestamp) => {
+                annotated.push_str(&line[copied..timestamp.end]);
+                annotated.push_str(&format!(" [{}]", human_readable(&timestamp, time_zone)));
+                counts[timestamp.kind as usize] += 1;
+                copied = timestamp.end;
+                i = timestamp.end;
+            }
+            // Skip the rest of the number, so its trailing digits are not matched on their own.
+            None => {
+                while i < bytes.len() && is_word_byte(bytes[i]) {
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    annotated.push_str(&line[copied..]);
+    annotated
+}
+
+/// Formats a timestamp in the time zone with the human-readable form of the date converter.
+fn human_readable(timestamp: &Timestamp, time_zone: Zone) -> String {
+    let format = match timestamp.precision {
+        0 => HUMAN_READABLE_FORMAT.to_string(),
+        1..=3 => HUMAN_READABLE_FORMAT.replace("%S", "%S%.3f"),
+        4..=6 => HUMAN_READABLE_FORMAT.replace("%S", "%S%.6f"),
+        _ => HUMAN_READABLE_FORMAT.replace("%S", "%S%.9f"),
+    };
+    format!(
+        "{} {}",
+        time_zone.convert(timestamp.datetime).format(&format),
+        time_zone.label()
+    )
+}
+
+/// Returns whether a byte can be part of a word or number, so it cannot border a timestamp.
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'.'
+}
+
+/// Returns the end of the run of ASCII digits starting at `start`.
+fn digits_end(bytes: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < bytes.len() && bytes[end].is_ascii_digit() {
+        end += 1;
+    }
+    end
+}
+
+/// Returns whether the timestamp ending at `end` is followed by a word boundary.
+fn at_boundary(bytes: &[u8], end: usize) -> bool {
+    match bytes.get(end) {
+        None => true,
+        Some(b'.') => !bytes.get(end + 1).is_some_and(u8::is_ascii_alphanumeric),
+        Some(&byte) => !is_word_byte(byte),
+    }
+}
+
+/// Parses an epoch timestamp starting at `start`, choosing the unit from its number of digits.
+fn parse_epoch(bytes: &[u8], start: usize) -> Option<Timestamp> {
+    let end = digits_end(bytes, start);
+    let digits = std::str::from_utf8(&bytes[start..end]).ok()?;
+    let value: i64 = digits.parse().ok()?;
+
+    let (end, datetime, precision, kind) = match digits.len() {
+        10 => {
+            // Seconds can carry a fraction, e.g. `1700000000.123456`, but a dot without digits
+            // after it ends the sentence instead.
+            let mut fraction_end = end;
+            if bytes.get(end) == Some(&b'.') {
+                let fraction_digits_end = digits_end(bytes, end + 1);
+                if fraction_digits_end > end + 1 {
+                    fraction_end = fraction_digits_end;
+                }
+            }
+            let fraction = std::str::from_utf8(&bytes[end..fraction_end])
+                .ok()?
+                .trim_start_matches('.');
+            let precision = fraction.len().min(9);
+            let nanos = if fraction.is_empty() {
+                0
+            } else {
+                format!("{:0<9}", &fraction[..precision]).parse().ok()?
+            };
+            (
+                fraction_end,
+                DateTime::from_timestamp(value, nanos)?,
+                precision,
+                TimestampKind::Seconds,
+            )
+        }
+        13 => (
+            end,
+            DateTime::from_timestamp_millis(value)?,
+            3,
+            TimestampKind::Milliseconds,
+        ),
+        16 => (
+            end,
+            DateTime::from_timestamp_micros(value)?,
+            6,
+            TimestampKind::Microseconds,
+        ),
+        19 => (
+            end,
+            DateTime::from_timestamp_nanos(value),
+            9,
+            TimestampKind::Nanoseconds,
+        ),
+        _ => return None,
+    };
+
+    // Numbers that happen to have the right length but decode to far away dates are ignored.
+    if !at_boundary(bytes, end) || !(1970..=2100).contains(&datetime.year()) {
+        return None;
+    }
+
+    Some(Timestamp {
+        end,
+        datetime,
+        precision,
+        kind,
+    })
+}
+
+/// Parses an ISO 8601 timestamp starting at `start`, such as `2024-03-22T10:00:00.123+05:30`.
+/// Timestamps without an offset are assumed to be in UTC.
+fn parse_iso8601(bytes: &[u8], start: usize) -> Option<Timestamp> {
+    // Match the fixed `YYYY-MM-DDTHH:MM:SS` part, with a `T` or a space between date and time.
+    const PATTERN: &[u8] = b"dddd-dd-ddTdd:dd:dd";
+    let fixed = bytes.get(start..start + PATTERN.len())?;
+    let matches = fixed
+        .iter()
+        .zip(PATTERN)
+        .all(|(byte, expected)| match expected {
+            b'd' => byte.is_ascii_digit(),
+            b'T' => matches!(byte, b'T' | b't' | b' '),
+            _ => byte == expected,
+        });
+    if !matches {
+        return None;
+    }
+    let mut end = start + PATTERN.len();
+
+    // An optional fraction, separated by a dot or a comma.
+    let mut precision = 0;
+    if matches!(bytes.get(end), Some(b'.') | Some(b',')) {
+        let fraction_end = digits_end(bytes, end + 1);
+        if fraction_end > end + 1 {
+            precision = fraction_end - end - 1;
+            end = fraction_end;
+        }
+    }
+    let date_time_end = end;
+
+    // An optional `Z` or numeric offset, with or without a colon or minutes.
+    let mut offset = None;
+    match bytes.get(end) {
+        Some(b'Z') | Some(b'z') => {
+            offset = Some("+00:00".to_string());
+            end += 1;
+        }
+        Some(b'+') | Some(b'-') => {
+            let sign = bytes[end] as char;
+            let hours_end = digits_end(bytes, end + 1);
+            if hours_end == end + 3 {
+                let hours = std::str::from_utf8(&bytes[end + 1..hours_end]).ok()?;
+                if bytes.get(hours_end) != Some(&b':') {
+                    // An offset with only hours, such as `+05`.
+                    offset = Some(format!("{}{}:00", sign, hours));
+                    end = hours_end;
+                } else if digits_end(bytes, hours_end + 1) == hours_end + 3 {
+                    let minutes = std::str::from_utf8(&bytes[hours_end + 1..hours_end + 3]).ok()?;
+                    offset = Some(format!("{}{}:{}", sign, hours, minutes));
+                    end = hours_end + 3;
+                } else {
+                    // A malformed offset would show the wrong instant, so it is not annotated.
+                    return None;
+                }
+            } else if hours_end == end + 5 {
+                let digits = std::str::from_utf8(&bytes[end + 1..hours_end]).ok()?;
+                offset = Some(format!("{}{}:{}", sign, &digits[..2], &digits[2..]));
+                end = hours_end;
+            } else if hours_end > end + 1 {
+                return None;
+            }
+        }
+        _ => {}
+    }
+
+    if !at_boundary(bytes, end) {
+        return None;
+    }
+
+    // Normalize the separators, so chrono can parse the timestamp in one format.
+    let date_time = std::str::from_utf8(&bytes[start..date_time_end])
+        .ok()?
+        .replacen([' ', 't'], "T", 1)
+        .replace(',', ".");
+    let datetime = match offset {
+        Some(offset) => DateTime::parse_from_str(
+            &format!("{}{}", date_time, offset),
+            "%Y-%m-%dT%H:%M:%S%.f%:z",
+        )
+        .ok()?
+        .to_utc(),
+        None => NaiveDateTime::parse_from_str(&date_time, "%Y-%m-%dT%H:%M:%S%.f")
+            .ok()?
+            .and_utc(),
+    };
+
+    Some(Timestamp {
+        end,
+        datetime,
+        precision,
+        kind: TimestampKind::Iso8601,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epoch_ends_before_a_trailing_dot_without_digits() {
+        let line = b"at 1700000000. Done";
+        assert_eq!(
+            parse_epoch(line, 3).map(|timestamp| timestamp.end),
+            Some(13)
+        );
+
+        let line = b"at 1700000000.25 Done";
+        assert_eq!(
+            parse_epoch(line, 3).map(|timestamp| timestamp.end),
+            Some(16)
+        );
+    }
+}
@@ -2,19 +2,31 @@
 /// for performing common data manipulations and conversions.
 ///
 ///  - **base64_encoder:** Implements functionality to encode and decode data using base64 encoding.
-///  - **color_converter:** Implements functionality to convert colors between different formats CMYK, HEX, HSL, & RGB.
+///  - **chmod_calculator:** Converts Unix file permissions between octal modes, symbolic strings and symbolic expressions.
+///  - **color_converter:** Converts colors between CMYK, HEX, HSL, HSV, HWB, RGB, XYZ, Lab, LCH, OKLab and OKLCH, checks their WCAG contrast, generates palettes, simulates color blindness, mixes colors into blends and gradients and extracts the dominant colors of an image.
+///  - **cron_explainer:** Explains cron expressions in plain English and lists their upcoming run times.
 ///  - **date_converter:** Provides tools to convert dates between different formats like RFC3339, RFC2822, ISO8601, Unix timestamps, and human-readable formats.
-///  - **hash_generator:** Generates various hash values (SHA-1, SHA-256, SHA-384, SHA-512) from an input string.
+///  - **hash_generator:** Generates various hash values (MD5, SHA-1, SHA-2, SHA3-256, BLAKE2b, BLAKE3) from an input string.
+///  - **log_decoder:** Detects epoch and ISO 8601 timestamps in log lines and annotates them with a human-readable date.
+///  - **network_calculator:** Calculates IPv4 and IPv6 network details from an address with a CIDR prefix or netmask and splits networks into subnets.
 ///  - **number_base_converter:**  Facilitates the conversion of numbers between different number bases.
 ///  - **password_generator:** Generates secure random passwords with configurable lengths and character sets.
 ///  - **qr_code_generator:** Encodes data into QR codes that can be visualized and scanned.
+///  - **time_zone:** Reads and converts between UTC, local time, fixed offsets and IANA time zones for the tools that work with dates.
+///  - **unicode_inspector:** Lists the code point, name, category and encodings of each character, flags invisible or confusable characters and shows normalization forms.
 ///  - **uuid_generator:** Generates universally unique identifiers (UUIDs) conforming to RFC4122.
 ///
 pub mod base64_encoder;
+pub mod chmod_calculator;
 pub mod color_converter;
+pub mod cron_explainer;
 pub mod date_converter;
 pub mod hash_generator;
+pub mod log_decoder;
+pub mod network_calculator;
 pub mod number_base_converter;
 pub mod password_generator;
 pub mod qr_code_generator;
+pub mod time_zone;
+pub mod unicode_inspector;
 pub mod uuid_generator;
@@ -0,0 +1,477 @@
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+
+/// The smallest number of subnets a network can be split into.
+const MIN_SUBNETS: u32 = 2;
+/// The largest number of subnets a network can be split into.
+const MAX_SUBNETS: u32 = 256;
+
+/// Struct to calculate IPv4 and IPv6 network details from an address and prefix.
+pub struct NetworkCalculator {
+    /// The input address with a CIDR prefix or netmask.
+    pub input: String,
+    /// The number of smaller subnets to split the network into.
+    pub subnet_count: u32,
+    /// The address as entered, its version and prefix length.
+    pub address: String,
+    /// The network address.
+    pub network: String,
+    /// The broadcast address (IPv4 only).
+    pub broadcast: String,
+    /// The first usable host address.
+    pub first_host: String,
+    /// The last usable host address.
+    pub last_host: String,
+    /// The total number of addresses and usable hosts.
+    pub host_count: String,
+    /// The netmask in dotted or colon notation.
+    pub netmask: String,
+    /// The wildcard (host) mask.
+    pub wildcard: String,
+    /// The binary representation of the address, netmask and network.
+    pub binary: String,
+    /// The address class and scope, e.g. private, loopback or link-local.
+    pub scope: String,
+    /// The network split into `subnet_count` smaller subnets.
+    pub subnets: String,
+    /// A potential message for tools export.
+    pub tools_export_message: Option<String>,
+}
+
+impl NetworkCalculator {
+    /// Creates a new instance of `NetworkCalculator`.
+    pub fn new() -> Self {
+        NetworkCalculator {
+            input: String::new(),
+            subnet_count: 4, // Default to splitting into 4 subnets
+            address: String::new(),
+            network: String::new(),
+            broadcast: String::new(),
+            first_host: String::new(),
+            last_host: String::new(),
+            host_count: String::new(),
+            netmask: String::new(),
+            wildcard: String::new(),
+            binary: String::new(),
+            scope: String::new(),
+            subnets: String::new(),
+            tools_export_message: None,
+        }
+    }
+
+    /// Parses the `input` and calculates all network details.
+    ///
+    /// The input is an IPv4 or IPv6 address, optionally followed by a CIDR prefix
+    /// (`192.168.1.10/24`, `2001:db8::1/64`) or a netmask (`192.168.1.10 255.255.255.0`).
+    /// Without a prefix the address is treated as a single host.
+    pub fn calculate(&mut self) {
+        let input = self.input.trim();
+        if input.is_empty() {
+            self.set_all_to("");
+            return;
+        }
+
+        match Network::parse(input) {
+            Ok(network) => self.update_outputs(&network),
+            Err(err) => self.set_all_to(&err),
+        }
+    }
+
+    /// Increases the number of subnets to split the network into.
+    pub fn increase_subnet_count(&mut self) {
+        if self.subnet_count < MAX_SUBNETS {
+            self.subnet_count += 1;
+        }
+    }
+
+    /// Decreases the number of subnets to split the network into.
+    pub fn decrease_subnet_count(&mut self) {
+        if self.subnet_count > MIN_SUBNETS {
+            self.subnet_count -= 1;
+        }
+    }
+
+    /// Refreshes all outputs from the parsed network.
+    fn update_outputs(&mut self, network: &Network) {
+        let version = if network.is_ipv4() { "IPv4" } else { "IPv6" };
+        self.address = format!(
+            "{}/{} ({})",
+            network.format(network.address),
+            network.prefix,
+            version
+        );
+        self.network = format!("{}/{}", network.format(network.network()), network.prefix);
+        self.broadcast = if network.is_ipv4() {
+            network.format(network.last())
+        } else {
+            "N/A (IPv6 has no broadcast address)".to_string()
+        };
+
+        let (first, last) = network.host_range();
+        self.first_host = network.format(first);
+        self.last_host = network.format(last);
+        self.host_count = format!(
+            "{}, {}",
+            plural(&network.address_count(), "address", "addresses"),
+            plural(&network.usable_host_count(), "usable host", "usable hosts")
+        );
+        self.netmask = network.format(network.mask());
+        self.wildcard = network.format(network.host_mask());
+        self.binary = format!(
+            "Address: {}\nNetmask: {}\nNetwork: {}",
+            network.binary(network.address),
+            network.binary(network.mask()),
+            network.binary(network.network())
+        );
+        self.scope = network.scope();
+        self.subnets = network.split(self.subnet_count);
+    }
+
+    /// Sets all output fields to the provided error message.
+    fn set_all_to(&mut self, message: &str) {
+        self.address = message.to_string();
+        self.network = message.to_string();
+        self.broadcast = message.to_string();
+        self.first_host = message.to_string();
+        self.last_host = message.to_string();
+        self.host_count = message.to_string();
+        self.netmask = message.to_string();
+        self.wildcard = message.to_string();
+        self.binary = message.to_string();
+        self.scope = message.to_string();
+        self.subnets = message.to_string();
+    }
+
+    /// Exports the input, network details and subnets to a file.
+    pub fn write_to_file(&self) -> std::io::Result<()> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new("export/network.txt");
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // Write the input and each network detail to the file.
+        writeln!(file, "Input: {}", self.input)?;
+        writeln!(file, "Address: {}", self.address)?;
+        writeln!(file, "Network: {}", self.network)?;
+        writeln!(file, "Broadcast: {}", self.broadcast)?;
+        writeln!(file, "First Host: {}", self.first_host)?;
+        writeln!(file, "Last Host: {}", self.last_host)?;
+        writeln!(file, "Hosts: {}", self.host_count)?;
+        writeln!(file, "Netmask: {}", self.netmask)?;
+        writeln!(file, "Wildcard Mask: {}", self.wildcard)?;
+        writeln!(file, "Scope: {}", self.scope)?;
+        writeln!(file, "\nBinary:\n{}", self.binary)?;
+        writeln!(file, "\nSubnets:\n{}", self.subnets)?;
+
+        // Return Ok(()) to indicate success.
+        Ok(())
+    }
+}
+
+/// An IPv4 or IPv6 address with its prefix length, stored as a 128-bit integer.
+struct Network {
+    /// The address as entered.
+    address: u128,
+    /// The prefix length in bits.
+    prefix: u32,
+    /// The address width in bits, 32 for IPv4 and 128 for IPv6.
+    width: u32,
+}
+
+impl Network {
+    /// Parses an address followed by an optional CIDR prefix or netmask.
+    fn parse(input: &str) -> Result<Network, String> {
+        let (address_part, prefix_part) = match input.split_once('/') {
+            Some((address, prefix)) => (address.trim(), Some(prefix.trim())),
+            None => match input.split_once(char::is_whitespace) {
+                Some((address, mask)) => (address.trim(), Some(mask.trim())),
+                None => (input, None),
+            },
+        };
+
+        let ip: IpAddr = address_part
+            .parse()
+            .map_err(|_| format!("Invalid IP address '{}'", address_part))?;
+        let (address, width) = match ip {
+            IpAddr::V4(ip) => (u32::from(ip) as u128, 32),
+            IpAddr::V6(ip) => (u128::from(ip), 128),
+        };
+
+        let prefix = match prefix_part {
+            None => width,
+            Some(part) => parse_prefix(part, width)?,
+        };
+
+        Ok(Network {
+            address,
+            prefix,
+            width,
+        })
+    }
+
+    /// Returns whether this is an IPv4 network.
+    fn is_ipv4(&self) -> bool {
+        self.width == 32
+    }
+
+    /// Returns the number of host bits.
+    fn host_bits(&self) -> u32 {
+        self.width - self.prefix
+    }
+
+    /// Returns the netmask.
+    fn mask(&self) -> u128 {
+        full_mask(self.width) & !self.host_mask()
+    }
+
+    /// Returns the wildcard (host) mask.
+    fn host_mask(&self) -> u128 {
+        full_mask(self.host_bits())
+    }
+
+    /// Returns the network address.
+    fn network(&self) -> u128 {
+        self.address & self.mask()
+    }
+
+    /// Returns the last address in the network (the broadcast address for IPv4).
+    fn last(&self) -> u128 {
+        self.network() | self.host_mask()
+    }
+
+    /// Returns the first and last usable host addresses.
+    ///
+    /// IPv4 networks reserve the network and broadcast addresses, except for
+    /// point-to-point `/31` (RFC 3021) and single host `/32` networks.
+    fn host_range(&self) -> (u128, u128) {
+        if self.is_ipv4() && self.host_bits() >= 2 {
+            (self.network() + 1, self.last() - 1)
+        } else {
+            (self.network(), self.last())
+        }
+    }
+
+    /// Returns the total number of addresses in the network.
+    fn address_count(&self) -> String {
+        power_of_two(self.host_bits())
+    }
+
+    /// Returns the number of usable host addresses in the network.
+    fn usable_host_count(&self) -> String {
+        if self.is_ipv4() && self.host_bits() >= 2 {
+            ((1u128 << self.host_bits()) - 2).to_string()
+        } else {
+            self.address_count()
+        }
+    }
+
+    /// Formats an address of this network's version.
+    fn format(&self, value: u128) -> String {
+        if self.is_ipv4() {
+            Ipv4Addr::from(value as u32).to_string()
+        } else {
+            Ipv6Addr::from(value).to_string()
+        }
+    }
+
+    /// Formats an address in binary, grouped into octets (IPv4) or hextets (IPv6).
+    ///
+    /// IPv6 addresses are split over two lines of 64 bits to keep them readable.
+    fn binary(&self, value: u128) -> String {
+        let (group_bits, separator) = if self.is_ipv4() { (8, ".") } else { (16, ":") };
+        let groups: Vec<String> = (0..self.width / group_bits)
+            .rev()
+            .map(|group| {
+                let bits = (value >> (group * group_bits)) & full_mask(group_bits);
+                format!("{:0width$b}", bits, width = group_bits as usize)
+            })
+            .collect();
+
+        if self.is_ipv4() {
+            groups.join(separator)
+        } else {
+            format!(
+                "{}:\n         {}",
+                groups[..4].join(separator),
+                groups[4..].join(separator)
+            )
+        }
+    }
+
+    /// Describes the address class and scope of the address.
+    fn scope(&self) -> String {
+        if self.is_ipv4() {
+            let ip = Ipv4Addr::from(self.address as u32);
+            let class = match ip.octets()[0] {
+                0..=127 => "A",
+                128..=191 => "B",
+                192..=223 => "C",
+                224..=239 => "D (multicast)",
+                _ => "E (reserved)",
+            };
+            format!("Class {}, {}", class, ipv4_scope(ip))
+        } else {
+            ipv6_scope(Ipv6Addr::from(self.address)).to_string()
+        }
+    }
+
+    /// Splits the network into at least `count` equally sized subnets.
+    ///
+    /// Subnets can only be split in powers of two, so the count is rounded up.
+    fn split(&self, count: u32) -> String {
+        let extra_bits = count.next_power_of_two().trailing_zeros();
+        let new_prefix = self.prefix + extra_bits;
+        if new_prefix > self.width {
+            return format!(
+                "Cannot split a /{} network into {} subnets",
+                self.prefix, count
+            );
+        }
+
+        let subnet_count = 1u128 << extra_bits;
+        let subnet_size_bits = self.width - new_prefix;
+        let mut lines = vec![format!(
+            "{} requested, {} subnets of /{} with {} addresses each",
+            count,
+            subnet_count,
+            new_prefix,
+            power_of_two(subnet_size_bits)
+        )];
+
+        for index in 0..subnet_count {
+            let subnet = Network {
+                address: self.network() | (index << subnet_size_bits),
+                prefix: new_prefix,
+                width: self.width,
+            };
+            let (first, last) = subnet.host_range();
+            lines.push(format!(
+                "{:>3}. {}/{}  ({} - {})",
+                index + 1,
+                self.format(subnet.network()),
+                new_prefix,
+                self.format(first),
+                self.format(last)
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Parses a CIDR prefix length or a netmask into a prefix length.
+fn parse_prefix(part: &str, width: u32) -> Result<u32, String> {
+    if let Ok(prefix) = part.parse::<u32>() {
+        if prefix > width {
+            return Err(format!(
+                "Invalid prefix /{}: must be between 0 and {}",
+                prefix, width
+            ));
+        }
+        return Ok(prefix);
+    }
+
+    let mask = match part.parse::<IpAddr>() {
+        Ok(IpAddr::V4(mask)) if width == 32 => u32::from(mask) as u128,
+        Ok(IpAddr::V6(mask)) if width == 128 => u128::from(mask),
+        _ => return Err(format!("Invalid prefix or netmask '{}'", part)),
+    };
+
+    // A valid netmask is a run of ones followed by a run of zeros.
+    let prefix = mask.count_ones();
+    if mask != full_mask(width) & !full_mask(width - prefix) {
+        return Err(format!(
+            "Invalid netmask '{}': the one bits must be contiguous",
+            part
+        ));
+    }
+
+    Ok(prefix)
+}
+
+/// Returns a mask with the lowest `bits` bits set.
+fn full_mask(bits: u32) -> u128 {
+    if bits >= 128 {
+        u128::MAX
+    } else {
+        (1u128 << bits) - 1
+    }
+}
+
+/// Returns 2 to the power of `bits` as a string, including 2^128.
+fn power_of_two(bits: u32) -> String {
+    if bits >= 128 {
+        "340282366920938463463374607431768211456".to_string()
+    } else {
+        (1u128 << bits).to_string()
+    }
+}
+
+/// Formats a count with the singular or plural form of a noun.
+fn plural(count: &str, singular: &str, plural: &str) -> String {
+    if count == "1" {
+        format!("{} {}", count, singular)
+    } else {
+        format!("{} {}", count, plural)
+    }
+}
+
+/// Describes the scope of an IPv4 address.
+fn ipv4_scope(ip: Ipv4Addr) -> &'static str {
+    let [a, b, c, _] = ip.octets();
+    if ip.is_unspecified() {
+        "unspecified"
+    } else if ip.is_loopback() {
+        "loopback"
+    } else if ip.is_private() {
+        "private (RFC 1918)"
+    } else if ip.is_link_local() {
+        "link-local"
+    } else if a == 100 && (64..128).contains(&b) {
+        "shared address space (carrier-grade NAT)"
+    } else if ip.is_broadcast() {
+        "limited broadcast"
+    } else if ip.is_multicast() {
+        "multicast"
+    } else if ip.is_documentation() {
+        "documentation"
+    } else if a == 198 && (b == 18 || b == 19) {
+        "benchmarking"
+    } else if a == 192 && b == 0 && c == 0 {
+        "IETF protocol assignments"
+    } else if a >= 240 {
+        "reserved"
+    } else {
+        "public"
+    }
+}
+
+/// Describes the scope of an IPv6 address.
+fn ipv6_scope(ip: Ipv6Addr) -> &'static str {
+    let first = ip.segments()[0];
+    if ip.is_unspecified() {
+        "Unspecified"
+    } else if ip.is_loopback() {
+        "Loopback"
+    } else if ip.is_multicast() {
+        "Multicast"
+    } else if first & 0xffc0 == 0xfe80 {
+        "Link-local unicast"
+    } else if first & 0xfe00 == 0xfc00 {
+        "Unique local (private)"
+    } else if first == 0x2001 && ip.segments()[1] == 0x0db8 {
+        "Documentation"
+    } else if ip.to_ipv4_mapped().is_some() {
+        "IPv4-mapped"
+    } else if first & 0xe000 == 0x2000 {
+        "Global unicast"
+    } else {
+        "Reserved"
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, Tz};
+
+/// A time zone dates are read or shown in, shared by the cron explainer, the date converter
+/// and the log decoder.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Zone {
+    Utc,
+    Local,
+    Fixed(FixedOffset),
+    /// A zone of the IANA time zone database, e.g. `America/New_York`, with its daylight
+    /// saving rules.
+    Named(Tz),
+}
+
+impl Zone {
+    /// Returns a short label for the time zone.
+    pub fn label(&self) -> String {
+        match self {
+            Zone::Utc => "UTC".to_string(),
+            Zone::Local => "Local".to_string(),
+            Zone::Fixed(offset) => format!("UTC{}", offset),
+            Zone::Named(tz) => tz.name().to_string(),
+        }
+    }
+
+    /// Returns the abbreviation of the time zone at an instant, e.g. `EDT`, along with
+    /// whether daylight saving time is in effect.
+    pub fn abbreviation(&self, datetime: DateTime<Utc>) -> (String, bool) {
+        match self {
+            Zone::Named(tz) => {
+                let local = datetime.with_timezone(tz);
+                (
+                    local.format("%Z").to_string(),
+                    !local.offset().dst_offset().is_zero(),
+                )
+            }
+            _ => (self.label(), false),
+        }
+    }
+
+    /// Returns the current wall clock time in this time zone.
+    pub fn now(&self) -> NaiveDateTime {
+        match self {
+            Zone::Utc => Utc::now().naive_utc(),
+            Zone::Local => Local::now().naive_local(),
+            Zone::Fixed(offset) => Utc::now().with_timezone(offset).naive_local(),
+            Zone::Named(tz) => Utc::now().with_timezone(tz).naive_local(),
+        }
+    }
+
+    /// Resolves a wall clock time in this time zone to an instant.
+    /// Returns `None` for times skipped by a daylight saving transition, and the earliest
+    /// instant for times repeated by one.
+    pub fn resolve(&self, naive: NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+        match self {
+            Zone::Utc => Some(Utc.from_utc_datetime(&naive).fixed_offset()),
+            Zone::Local => Local
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|datetime| datetime.fixed_offset()),
+            Zone::Fixed(offset) => offset.from_local_datetime(&naive).single(),
+            Zone::Named(tz) => tz
+                .from_local_datetime(&naive)
+                .earliest()
+                .map(|datetime| datetime.fixed_offset()),
+        }
+    }
+
+    /// Converts an instant to the wall clock time in this time zone.
+    pub fn convert(&self, datetime: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self {
+            Zone::Utc => datetime.fixed_offset(),
+            Zone::Local => datetime.with_timezone(&Local).fixed_offset(),
+            Zone::Fixed(offset) => datetime.with_timezone(offset),
+            Zone::Named(tz) => datetime.with_timezone(tz).fixed_offset(),
+        }
+    }
+
+    /// Parses a zone such as a `CRON_TZ=` or `TZ=` value: `UTC`, `Local`, a fixed offset such
+    /// as `+05:30` or an IANA zone name such as `America/New_York`.
+    pub fn parse(value: &str) -> Option<Zone> {
+        if value.eq_ignore_ascii_case("UTC") || value.eq_ignore_ascii_case("Z") {
+            return Some(Zone::Utc);
+        }
+        if value.eq_ignore_ascii_case("Local") {
+            return Some(Zone::Local);
+        }
+        if let Ok(tz) = value.parse::<Tz>() {
+            return Some(Zone::Named(tz));
+        }
+
+        let sign = match value.chars().next()? {
+            '+' => 1,
+            '-' => -1,
+            _ => return None,
+        };
+        let digits = value[1..].replace(':', "");
+        if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let hours: i32 = digits[..2].parse().ok()?;
+        let minutes: i32 = digits[2..].parse().ok()?;
+        if minutes > 59 {
+            return None;
+        }
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).map(Zone::Fixed)
+    }
+}
@@ -0,0 +1,441 @@
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::Path;
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::UnicodeNormalization;
+use unicode_width::UnicodeWidthStr;
+
+/// Zero-width characters that are easy to miss when copied along with text.
+const ZERO_WIDTH: [char; 6] = [
+    '\u{200B}', '\u{200C}', '\u{200D}', '\u{2060}', '\u{FEFF}', '\u{180E}',
+];
+
+/// Bidirectional formatting characters, which can reorder how text is displayed.
+const BIDI_CONTROLS: [char; 12] = [
+    '\u{061C}', '\u{200E}', '\u{200F}', '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}',
+    '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+/// The characters Windows-1252 maps bytes `0x80` to `0x9F` to, used to detect mojibake.
+const WINDOWS_1252: [(char, u8); 27] = [
+    ('€', 0x80),
+    ('‚', 0x82),
+    ('ƒ', 0x83),
+    ('„', 0x84),
+    ('…', 0x85),
+    ('†', 0x86),
+    ('‡', 0x87),
+    ('ˆ', 0x88),
+    ('‰', 0x89),
+    ('Š', 0x8A),
+    ('‹', 0x8B),
+    ('Œ', 0x8C),
+    ('Ž', 0x8E),
+    ('‘', 0x91),
+    ('’', 0x92),
+    ('“', 0x93),
+    ('”', 0x94),
+    ('•', 0x95),
+    ('–', 0x96),
+    ('—', 0x97),
+    ('˜', 0x98),
+    ('™', 0x99),
+    ('š', 0x9A),
+    ('›', 0x9B),
+    ('œ', 0x9C),
+    ('ž', 0x9E),
+    ('Ÿ', 0x9F),
+];
+
+/// Whether the input is inspected as text or looked up by code point or name.
+#[derive(PartialEq)]
+pub enum UnicodeMode {
+    Inspect,
+    Lookup,
+}
+
+impl UnicodeMode {
+    /// Returns the name of the mode, as shown in the UI.
+    pub fn label(&self) -> &'static str {
+        match self {
+            UnicodeMode::Inspect => "Inspect Text",
+            UnicodeMode::Lookup => "Lookup by Code Point or Name",
+        }
+    }
+}
+
+/// Struct to inspect the characters of a string, or look characters up by code point or name.
+pub struct UnicodeInspector {
+    /// The input text, or code points and names in lookup mode.
+    pub input: String,
+    /// Whether the input is inspected or looked up.
+    pub mode: UnicodeMode,
+    /// A table with one row per character.
+    pub characters: String,
+    /// Character, byte and flag counts, and possible mojibake.
+    pub summary: String,
+    /// The NFC, NFD, NFKC and NFKD normalization forms.
+    pub normalization: String,
+    /// A potential message for tools export.
+    pub tools_export_message: Option<String>,
+}
+
+impl UnicodeInspector {
+    /// Creates a new instance of `UnicodeInspector`.
+    pub fn new() -> Self {
+        UnicodeInspector {
+            input: String::new(),
+            mode: UnicodeMode::Inspect,
+            characters: String::new(),
+            summary: String::new(),
+            normalization: String::new(),
+            tools_export_message: None,
+        }
+    }
+
+    /// Switches between inspecting text and looking characters up.
+    pub fn toggle_mode(&mut self) {
+        self.mode = match self.mode {
+            UnicodeMode::Inspect => UnicodeMode::Lookup,
+            UnicodeMode::Lookup => UnicodeMode::Inspect,
+        };
+    }
+
+    /// Inspects the `input`, or the characters it looks up, and updates all outputs.
+    pub fn inspect(&mut self) {
+        if self.input.is_empty() {
+            self.characters.clear();
+            self.summary.clear();
+            self.normalization.clear();
+            return;
+        }
+
+        let text = match self.mode {
+            UnicodeMode::Inspect => self.input.clone(),
+            UnicodeMode::Lookup => match lookup(&self.input) {
+                Ok(text) => text,
+                Err(err) => {
+                    self.characters = err.clone();
+                    self.summary = err.clone();
+                    self.normalization = err;
+                    return;
+                }
+            },
+        };
+
+        self.characters = character_table(&text);
+        self.summary = summarize(&text);
+        self.normalization = normalization_forms(&text);
+    }
+
+    /// Exports the input, character table, summary and normalization forms to a file.
+    pub fn write_to_file(&self) -> std::io::Result<()> {
+        // Create the "export" directory if it doesn't exist.
+        let file_path = Path::new("export/unicode.txt");
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        // Open the file for writing.
+        let mut file = File::create(file_path)?;
+
+        // Write the input and each output to the file.
+        writeln!(file, "Input: {}", self.input)?;
+        writeln!(file, "Mode: {}", self.mode.label())?;
+        writeln!(file, "\nSummary:\n{}", self.summary)?;
+        writeln!(file, "\nCharacters:\n{}", self.characters)?;
+        writeln!(file, "\nNormalization:\n{}", self.normalization)?;
+
+        // Return Ok(()) to indicate success.
+        Ok(())
+    }
+}
+
+/// Resolves each line of the input to characters.
+///
+/// A line is either one or more code points (`U+00E9`, `0xE9`, `\u{E9}`) separated by
+/// whitespace, or a character name such as `LATIN SMALL LETTER E WITH ACUTE`.
+fn lookup(input: &str) -> Result<String, String> {
+    let mut text = String::new();
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let code_points: Option<Vec<u32>> = line.split_whitespace().map(parse_code_point).collect();
+        match code_points {
+            Some(code_points) => {
+                for code_point in code_points {
+                    let c = char::from_u32(code_point).ok_or_else(|| {
+                        format!(
+                            "U+{:04X} is not a valid character (surrogate or out of range)",
+                            code_point
+                        )
+                    })?;
+                    text.push(c);
+                }
+            }
+            None => {
+                let c = unicode_names2::character(line)
+                    .ok_or_else(|| format!("No character named '{}'", line))?;
+                text.push(c);
+            }
+        }
+    }
+    Ok(text)
+}
+
+/// Parses a code point written as `U+XXXX`, `0xXXXX` or `\u{XXXX}`.
+fn parse_code_point(token: &str) -> Option<u32> {
+    let hex = token
+        .strip_prefix("U+")
+        .or_else(|| token.strip_prefix("u+"))
+        .or_else(|| token.strip_prefix("0x"))
+        .or_else(|| token.strip_prefix("0X"))
+        .or_else(|| {
+            token
+                .strip_prefix("\\u{")
+                .and_then(|rest| rest.strip_suffix('}'))
+        })
+        .or_else(|| token.strip_prefix("\\u"))?;
+    if hex.is_empty() || hex.len() > 6 {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok()
+}
+
+/// Builds a table with the code point, category, encodings, flags and name of each character.
+fn character_table(text: &str) -> String {
+    let mut lines = vec![format!(
+        "{:<4} {:<10} {:<4} {:<12} {:<10} {:<30} {}",
+        "Char", "Code Point", "Cat", "UTF-8", "UTF-16", "Flags", "Name"
+    )];
+
+    for c in text.chars() {
+        let glyph = display_glyph(c);
+        let padding = 4usize.saturating_sub(glyph.width());
+        lines.push(format!(
+            "{}{} {:<10} {:<4} {:<12} {:<10} {:<30} {}",
+            glyph,
+            " ".repeat(padding),
+            format!("U+{:04X}", c as u32),
+            get_general_category(c).abbreviation(),
+            utf8_bytes(c),
+            utf16_units(c),
+            flags(c).join(", "),
+            character_name(c)
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Summarizes the text: character and byte counts, flagged characters and possible mojibake.
+fn summarize(text: &str) -> String {
+    let flagged = text.chars().filter(|&c| !flags(c).is_empty()).count();
+    let mut lines = vec![
+        format!("Characters: {}", text.chars().count()),
+        format!("UTF-8 Bytes: {}", text.len()),
+        format!("UTF-16 Code Units: {}", text.encode_utf16().count()),
+        format!("Flagged Characters: {}", flagged),
+    ];
+
+    if let Some(repaired) = repair_mojibake(text) {
+        lines.push(format!(
+            "Possible Mojibake: UTF-8 decoded as Windows-1252, originally \"{}\"",
+            repaired.chars().map(display_glyph).collect::<String>()
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Shows each normalization form, its code points and whether it differs from the text.
+fn normalization_forms(text: &str) -> String {
+    let forms = [
+        ("NFC", text.nfc().collect::<String>()),
+        ("NFD", text.nfd().collect::<String>()),
+        ("NFKC", text.nfkc().collect::<String>()),
+        ("NFKD", text.nfkd().collect::<String>()),
+    ];
+
+    forms
+        .iter()
+        .map(|(name, normalized)| {
+            let status = if normalized == text {
+                "unchanged"
+            } else {
+                "changed"
+            };
+            let code_points = normalized
+                .chars()
+                .map(|c| format!("U+{:04X}", c as u32))
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!(
+                "{} ({}): {}\n{}",
+                name,
+                status,
+                normalized.chars().map(display_glyph).collect::<String>(),
+                code_points
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns a visible stand-in for characters that would not render or would break the layout.
+fn display_glyph(c: char) -> String {
+    match get_general_category(c) {
+        GeneralCategory::Control => match c as u32 {
+            code @ 0x00..=0x1F => char::from_u32(0x2400 + code).unwrap_or('◌').to_string(),
+            0x7F => "␡".to_string(),
+            _ => "◌".to_string(),
+        },
+        GeneralCategory::NonspacingMark
+        | GeneralCategory::EnclosingMark
+        | GeneralCategory::SpacingMark => format!("◌{}", c),
+        GeneralCategory::Format
+        | GeneralCategory::LineSeparator
+        | GeneralCategory::ParagraphSeparator
+        | GeneralCategory::Unassigned
+        | GeneralCategory::Surrogate
+        | GeneralCategory::PrivateUse => "◌".to_string(),
+        GeneralCategory::SpaceSeparator if c != ' ' => "␣".to_string(),
+        _ => c.to_string(),
+    }
+}
+
+/// Returns the UTF-8 bytes of a character in hexadecimal.
+fn utf8_bytes(c: char) -> String {
+    let mut buffer = [0; 4];
+    c.encode_utf8(&mut buffer)
+        .bytes()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the UTF-16 code units of a character in hexadecimal.
+fn utf16_units(c: char) -> String {
+    let mut buffer = [0; 2];
+    c.encode_utf16(&mut buffer)
+        .iter()
+        .map(|unit| format!("{:04X}", unit))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the official name of a character, or a descriptive label for unnamed ones.
+fn character_name(c: char) -> String {
+    if let Some(name) = unicode_names2::name(c) {
+        return name.to_string();
+    }
+
+    match get_general_category(c) {
+        GeneralCategory::Control => match c {
+            '\0' => "<control> NULL".to_string(),
+            '\t' => "<control> CHARACTER TABULATION".to_string(),
+            '\n' => "<control> LINE FEED".to_string(),
+            '\r' => "<control> CARRIAGE RETURN".to_string(),
+            '\u{1B}' => "<control> ESCAPE".to_string(),
+            '\u{7F}' => "<control> DELETE".to_string(),
+            '\u{85}' => "<control> NEXT LINE".to_string(),
+            _ => "<control>".to_string(),
+        },
+        GeneralCategory::PrivateUse => "<private use>".to_string(),
+        GeneralCategory::Unassigned if is_noncharacter(c) => "<noncharacter>".to_string(),
+        GeneralCategory::Unassigned => "<unassigned>".to_string(),
+        _ => "<unnamed>".to_string(),
+    }
+}
+
+/// Returns the warnings for a character: invisible, bidi, confusable or otherwise suspicious.
+fn flags(c: char) -> Vec<String> {
+    let mut flags = Vec::new();
+    let category = get_general_category(c);
+
+    if ZERO_WIDTH.contains(&c) {
+        flags.push("zero-width".to_string());
+    } else if BIDI_CONTROLS.contains(&c) {
+        flags.push("bidi control".to_string());
+    } else if category == GeneralCategory::Format {
+        flags.push("invisible".to_string());
+    }
+
+    if category == GeneralCategory::Control && c != '\n' && c != '\t' {
+        flags.push("control".to_string());
+    }
+    if matches!(
+        category,
+        GeneralCategory::SpaceSeparator
+            | GeneralCategory::LineSeparator
+            | GeneralCategory::ParagraphSeparator
+    ) && c != ' '
+    {
+        flags.push("non-ASCII space".to_string());
+    }
+    if c == '\u{FFFD}' {
+        flags.push("replacement char".to_string());
+    }
+    if category == GeneralCategory::PrivateUse {
+        flags.push("private use".to_string());
+    }
+    if category == GeneralCategory::Unassigned {
+        flags.push("unassigned".to_string());
+    }
+
+    // A non-ASCII character whose confusable skeleton is plain ASCII looks like that ASCII text.
+    if !c.is_ascii() {
+        let mut buffer = [0; 4];
+        let skeleton: String = unicode_security::skeleton(c.encode_utf8(&mut buffer)).collect();
+        if skeleton.is_ascii() && !skeleton.trim().is_empty() {
+            flags.push(format!("confusable with '{}'", skeleton));
+        }
+    }
+
+    flags
+}
+
+/// Returns whether a character is one of the 66 permanently reserved noncharacters.
+fn is_noncharacter(c: char) -> bool {
+    let code = c as u32;
+    (0xFDD0..=0xFDEF).contains(&code) || code & 0xFFFE == 0xFFFE
+}
+
+/// Detects UTF-8 text that was decoded as Windows-1252 or Latin-1 (e.g. `Ã©` for `é`),
+/// returning the text with every such run of characters decoded again.
+fn repair_mojibake(text: &str) -> Option<String> {
+    let mut repaired = String::with_capacity(text.len());
+    let mut run = String::new();
+    let mut changed = false;
+
+    // Characters that map back to a single byte are collected into runs, each run
+    // is re-decoded as UTF-8 and kept as is when that fails.
+    for c in text.chars().map(Some).chain(std::iter::once(None)) {
+        if let Some(c) = c.filter(|&c| windows_1252_byte(c).is_some()) {
+            run.push(c);
+            continue;
+        }
+
+        let bytes: Vec<u8> = run.chars().filter_map(windows_1252_byte).collect();
+        match String::from_utf8(bytes) {
+            Ok(decoded) if !decoded.is_ascii() => {
+                repaired.push_str(&decoded);
+                changed = true;
+            }
+            _ => repaired.push_str(&run),
+        }
+        run.clear();
+        repaired.extend(c);
+    }
+
+    changed.then_some(repaired)
+}
+
+/// Returns the byte a character is encoded as in Windows-1252, falling back to Latin-1.
+fn windows_1252_byte(c: char) -> Option<u8> {
+    match c as u32 {
+        code @ 0x00..=0xFF => Some(code as u8),
+        _ => WINDOWS_1252
+            .iter()
+            .find(|(mapped, _)| *mapped == c)
+            .map(|(_, byte)| *byte),
+    }
+}
@@ -1,4 +1,9 @@
 use crate::app::{App, Tool};
+use crate::tools::chmod_calculator;
+use crate::tools::color_converter::{self, ColorMode};
+use crate::tools::date_converter::DateMode;
+use crate::tools::hash_generator::HashAlgorithm;
+use chrono::Datelike;
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     backend::Backend,
@@ -6,7 +11,7 @@ use ratatui::{
     prelude::*,
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Padding, Paragraph, Tabs, Wrap},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph, Tabs, Wrap},
     Frame, Terminal,
 };
 use std::io;
@@ -16,15 +21,21 @@ use tui_textarea::{CursorMove, Input, Key, TextArea};
 ///
 /// This function is responsible for managing the interaction between the user,
 /// the terminal, and the various tools available in the application.
+#[allow(clippy::too_many_arguments)]
 pub fn run_app<B: Backend>(
     terminal: &mut Terminal<B>,
     mut app: App,
     base64_converter_textarea: &mut TextArea,
+    chmod_calculator_textarea: &mut TextArea,
     color_converter_textarea: &mut TextArea,
+    cron_explainer_textarea: &mut TextArea,
     date_converter_textarea: &mut TextArea,
     hash_generator_textarea: &mut TextArea,
+    log_decoder_textarea: &mut TextArea,
+    network_calculator_textarea: &mut TextArea,
     number_base_converter_textarea: &mut TextArea,
     qr_code_generator_textarea: &mut TextArea,
+    unicode_inspector_textarea: &mut TextArea,
 ) -> io::Result<()> {
     // Start an infinite loop, continuously handling user input and redrawing the User Interface.
     loop {
@@ -34,14 +45,28 @@ pub fn run_app<B: Backend>(
                 f,                              // A mutable reference to the terminal's frame.
                 &mut app,                       // A mutable reference to the application state.
                 base64_converter_textarea,      // Text area for base64 conversion.
+                chmod_calculator_textarea,      // Text area for file permission calculation.
                 color_converter_textarea,       // Text area for color code conversion.
+                cron_explainer_textarea,        // Text area for cron expression explanation.
                 date_converter_textarea,        // Text area for date conversion.
                 hash_generator_textarea,        // Text area for hash generation.
+                log_decoder_textarea,           // Text area for log timestamp decoding.
+                network_calculator_textarea,    // Text area for IP address and subnet calculation.
                 number_base_converter_textarea, // Text area for number base conversion.
                 qr_code_generator_textarea,     // Text area for QR code generation.
+                unicode_inspector_textarea,     // Text area for unicode character inspection.
             )
         })?;
 
+        // While the world clock is shown, wait for input only until its next second to redraw it.
+        if app.current_tool == Tool::DateConverter {
+            if let Some(tick) = app.date_converter.clock_tick() {
+                if !crossterm::event::poll(tick)? {
+                    continue;
+                }
+            }
+        }
+
         // Read the next user input event (keyboard, mouse).
         match crossterm::event::read()? {
             // Handle keyboard inputs
@@ -62,13 +87,18 @@ pub fn run_app<B: Backend>(
                     // Switch between different tools using the Tab key.
                     KeyCode::Tab => {
                         app.current_tool = match app.current_tool {
-                            Tool::Base64Encoder => Tool::ColorConverter,
-                            Tool::ColorConverter => Tool::DateConverter,
+                            Tool::Base64Encoder => Tool::ChmodCalculator,
+                            Tool::ChmodCalculator => Tool::ColorConverter,
+                            Tool::ColorConverter => Tool::CronExplainer,
+                            Tool::CronExplainer => Tool::DateConverter,
                             Tool::DateConverter => Tool::HashGenerator,
-                            Tool::HashGenerator => Tool::NumberBaseConverter,
+                            Tool::HashGenerator => Tool::LogDecoder,
+                            Tool::LogDecoder => Tool::NetworkCalculator,
+                            Tool::NetworkCalculator => Tool::NumberBaseConverter,
                             Tool::NumberBaseConverter => Tool::PasswordGenerator,
                             Tool::PasswordGenerator => Tool::QRCodeGenerator,
-                            Tool::QRCodeGenerator => Tool::UuidGenerator,
+                            Tool::QRCodeGenerator => Tool::UnicodeInspector,
+                            Tool::UnicodeInspector => Tool::UuidGenerator,
                             Tool::UuidGenerator => Tool::Base64Encoder,
                         };
                         // Skip processing of other input events this time as switching tools is a dedicated action.
@@ -77,28 +107,51 @@ pub fn run_app<B: Backend>(
 
                     // Handle Left, Right, Up, and Down keys to move cursor.
                     KeyCode::Left => {
+                        // Move the date converter's calendar by a day when it is shown.
+                        if app.current_tool == Tool::DateConverter
+                            && app.date_converter.calendar_moves_date()
+                        {
+                            move_date_calendar(&mut app, date_converter_textarea, -1, 0);
+                            continue;
+                        }
                         // Handle Ctrl + Left Arrow to move cursor word back.
                         if key.modifiers.contains(KeyModifiers::CONTROL) {
                             base64_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordBack);
+                            chmod_calculator_textarea
+                                .move_cursor(tui_textarea::CursorMove::WordBack);
                             color_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordBack);
+                            cron_explainer_textarea.move_cursor(tui_textarea::CursorMove::WordBack);
                             date_converter_textarea.move_cursor(tui_textarea::CursorMove::WordBack);
                             number_base_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordBack);
                             hash_generator_textarea.move_cursor(tui_textarea::CursorMove::WordBack);
+                            log_decoder_textarea.move_cursor(tui_textarea::CursorMove::WordBack);
+                            network_calculator_textarea
+                                .move_cursor(tui_textarea::CursorMove::WordBack);
                             qr_code_generator_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordBack);
+                            unicode_inspector_textarea
+                                .move_cursor(tui_textarea::CursorMove::WordBack);
                         // Handle Shift + Left Arrow to select the entire line (to the left).
                         } else if key.modifiers.contains(KeyModifiers::SHIFT) {
                             base64_converter_textarea.move_cursor(CursorMove::Head);
                             base64_converter_textarea.start_selection();
                             base64_converter_textarea.move_cursor(CursorMove::End);
 
+                            chmod_calculator_textarea.move_cursor(CursorMove::Head);
+                            chmod_calculator_textarea.start_selection();
+                            chmod_calculator_textarea.move_cursor(CursorMove::End);
+
                             color_converter_textarea.move_cursor(CursorMove::Head);
                             color_converter_textarea.start_selection();
                             color_converter_textarea.move_cursor(CursorMove::End);
 
+                            cron_explainer_textarea.move_cursor(CursorMove::Head);
+                            cron_explainer_textarea.start_selection();
+                            cron_explainer_textarea.move_cursor(CursorMove::End);
+
                             date_converter_textarea.move_cursor(CursorMove::Head);
                             date_converter_textarea.start_selection();
                             date_converter_textarea.move_cursor(CursorMove::End);
@@ -107,6 +160,14 @@ pub fn run_app<B: Backend>(
                             hash_generator_textarea.start_selection();
                             hash_generator_textarea.move_cursor(CursorMove::End);
 
+                            log_decoder_textarea.move_cursor(CursorMove::Head);
+                            log_decoder_textarea.start_selection();
+                            log_decoder_textarea.move_cursor(CursorMove::End);
+
+                            network_calculator_textarea.move_cursor(CursorMove::Head);
+                            network_calculator_textarea.start_selection();
+                            network_calculator_textarea.move_cursor(CursorMove::End);
+
                             number_base_converter_textarea.move_cursor(CursorMove::Head);
                             number_base_converter_textarea.start_selection();
                             number_base_converter_textarea.move_cursor(CursorMove::End);
@@ -114,26 +175,43 @@ pub fn run_app<B: Backend>(
                             qr_code_generator_textarea.move_cursor(CursorMove::Head);
                             qr_code_generator_textarea.start_selection();
                             qr_code_generator_textarea.move_cursor(CursorMove::End);
+                            unicode_inspector_textarea.move_cursor(CursorMove::Head);
+                            unicode_inspector_textarea.start_selection();
+                            unicode_inspector_textarea.move_cursor(CursorMove::End);
                         // Handle normal Left Arrow to move cursor back one character.
                         } else {
                             base64_converter_textarea.move_cursor(tui_textarea::CursorMove::Back);
                             base64_converter_textarea.cancel_selection();
 
+                            chmod_calculator_textarea.move_cursor(tui_textarea::CursorMove::Back);
+                            chmod_calculator_textarea.cancel_selection();
+
                             color_converter_textarea.move_cursor(tui_textarea::CursorMove::Back);
                             color_converter_textarea.cancel_selection();
 
+                            cron_explainer_textarea.move_cursor(tui_textarea::CursorMove::Back);
+                            cron_explainer_textarea.cancel_selection();
+
                             date_converter_textarea.move_cursor(tui_textarea::CursorMove::Back);
                             date_converter_textarea.cancel_selection();
 
                             hash_generator_textarea.move_cursor(tui_textarea::CursorMove::Back);
                             hash_generator_textarea.cancel_selection();
 
+                            log_decoder_textarea.move_cursor(tui_textarea::CursorMove::Back);
+                            log_decoder_textarea.cancel_selection();
+
+                            network_calculator_textarea.move_cursor(tui_textarea::CursorMove::Back);
+                            network_calculator_textarea.cancel_selection();
+
                             number_base_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::Back);
                             number_base_converter_textarea.cancel_selection();
 
                             qr_code_generator_textarea.move_cursor(tui_textarea::CursorMove::Back);
                             qr_code_generator_textarea.cancel_selection();
+                            unicode_inspector_textarea.move_cursor(tui_textarea::CursorMove::Back);
+                            unicode_inspector_textarea.cancel_selection();
                         }
                         // Continue the loop as cursor movement is a continuous action.
                         continue;
@@ -142,30 +220,54 @@ pub fn run_app<B: Backend>(
                     // Handle Right, Up, and Down arrow keys similar to Left Arrow,
                     //  but for corresponding directions.
                     KeyCode::Right => {
+                        // Move the date converter's calendar by a day when it is shown.
+                        if app.current_tool == Tool::DateConverter
+                            && app.date_converter.calendar_moves_date()
+                        {
+                            move_date_calendar(&mut app, date_converter_textarea, 1, 0);
+                            continue;
+                        }
                         if key.modifiers.contains(KeyModifiers::CONTROL) {
                             base64_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordForward);
+                            chmod_calculator_textarea
+                                .move_cursor(tui_textarea::CursorMove::WordForward);
                             color_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordForward);
+                            cron_explainer_textarea
+                                .move_cursor(tui_textarea::CursorMove::WordForward);
                             date_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordForward);
                             hash_generator_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordForward);
+                            log_decoder_textarea.move_cursor(tui_textarea::CursorMove::WordForward);
+                            network_calculator_textarea
+                                .move_cursor(tui_textarea::CursorMove::WordForward);
                             number_base_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordForward);
                             qr_code_generator_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordForward);
                             qr_code_generator_textarea
                                 .move_cursor(tui_textarea::CursorMove::WordForward);
+                            unicode_inspector_textarea
+                                .move_cursor(tui_textarea::CursorMove::WordForward);
                         } else if key.modifiers.contains(KeyModifiers::SHIFT) {
                             base64_converter_textarea.move_cursor(CursorMove::Head);
                             base64_converter_textarea.start_selection();
                             base64_converter_textarea.move_cursor(CursorMove::End);
 
+                            chmod_calculator_textarea.move_cursor(CursorMove::Head);
+                            chmod_calculator_textarea.start_selection();
+                            chmod_calculator_textarea.move_cursor(CursorMove::End);
+
                             color_converter_textarea.move_cursor(CursorMove::Head);
                             color_converter_textarea.start_selection();
                             color_converter_textarea.move_cursor(CursorMove::End);
 
+                            cron_explainer_textarea.move_cursor(CursorMove::Head);
+                            cron_explainer_textarea.start_selection();
+                            cron_explainer_textarea.move_cursor(CursorMove::End);
+
                             date_converter_textarea.move_cursor(CursorMove::Head);
                             date_converter_textarea.start_selection();
                             date_converter_textarea.move_cursor(CursorMove::End);
@@ -174,6 +276,14 @@ pub fn run_app<B: Backend>(
                             hash_generator_textarea.start_selection();
                             hash_generator_textarea.move_cursor(CursorMove::End);
 
+                            log_decoder_textarea.move_cursor(CursorMove::Head);
+                            log_decoder_textarea.start_selection();
+                            log_decoder_textarea.move_cursor(CursorMove::End);
+
+                            network_calculator_textarea.move_cursor(CursorMove::Head);
+                            network_calculator_textarea.start_selection();
+                            network_calculator_textarea.move_cursor(CursorMove::End);
+
                             number_base_converter_textarea.move_cursor(CursorMove::Head);
                             number_base_converter_textarea.start_selection();
                             number_base_converter_textarea.move_cursor(CursorMove::End);
@@ -181,20 +291,37 @@ pub fn run_app<B: Backend>(
                             qr_code_generator_textarea.move_cursor(CursorMove::Head);
                             qr_code_generator_textarea.start_selection();
                             qr_code_generator_textarea.move_cursor(CursorMove::End);
+                            unicode_inspector_textarea.move_cursor(CursorMove::Head);
+                            unicode_inspector_textarea.start_selection();
+                            unicode_inspector_textarea.move_cursor(CursorMove::End);
                         } else {
                             base64_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::Forward);
                             base64_converter_textarea.cancel_selection();
 
+                            chmod_calculator_textarea
+                                .move_cursor(tui_textarea::CursorMove::Forward);
+                            chmod_calculator_textarea.cancel_selection();
+
                             color_converter_textarea.move_cursor(tui_textarea::CursorMove::Forward);
                             color_converter_textarea.cancel_selection();
 
+                            cron_explainer_textarea.move_cursor(tui_textarea::CursorMove::Forward);
+                            cron_explainer_textarea.cancel_selection();
+
                             date_converter_textarea.move_cursor(tui_textarea::CursorMove::Forward);
                             date_converter_textarea.cancel_selection();
 
                             hash_generator_textarea.move_cursor(tui_textarea::CursorMove::Forward);
                             hash_generator_textarea.cancel_selection();
 
+                            log_decoder_textarea.move_cursor(tui_textarea::CursorMove::Forward);
+                            log_decoder_textarea.cancel_selection();
+
+                            network_calculator_textarea
+                                .move_cursor(tui_textarea::CursorMove::Forward);
+                            network_calculator_textarea.cancel_selection();
+
                             number_base_converter_textarea
                                 .move_cursor(tui_textarea::CursorMove::Forward);
                             number_base_converter_textarea.cancel_selection();
@@ -202,30 +329,57 @@ pub fn run_app<B: Backend>(
                             qr_code_generator_textarea
                                 .move_cursor(tui_textarea::CursorMove::Forward);
                             qr_code_generator_textarea.cancel_selection();
+                            unicode_inspector_textarea
+                                .move_cursor(tui_textarea::CursorMove::Forward);
+                            unicode_inspector_textarea.cancel_selection();
                         }
                         continue;
                     }
                     // Handle Right, Up, and Down arrow keys similar to Left Arrow,
                     //  but for corresponding directions.
                     KeyCode::Up => {
+                        // Move the date converter's calendar by a week when it is shown.
+                        if app.current_tool == Tool::DateConverter
+                            && app.date_converter.calendar_moves_date()
+                        {
+                            move_date_calendar(&mut app, date_converter_textarea, -7, 0);
+                            continue;
+                        }
                         base64_converter_textarea.move_cursor(tui_textarea::CursorMove::Up);
+                        chmod_calculator_textarea.move_cursor(tui_textarea::CursorMove::Up);
                         color_converter_textarea.move_cursor(tui_textarea::CursorMove::Up);
+                        cron_explainer_textarea.move_cursor(tui_textarea::CursorMove::Up);
                         date_converter_textarea.move_cursor(tui_textarea::CursorMove::Up);
                         hash_generator_textarea.move_cursor(tui_textarea::CursorMove::Up);
+                        log_decoder_textarea.move_cursor(tui_textarea::CursorMove::Up);
+                        network_calculator_textarea.move_cursor(tui_textarea::CursorMove::Up);
                         number_base_converter_textarea.move_cursor(tui_textarea::CursorMove::Up);
                         qr_code_generator_textarea.move_cursor(tui_textarea::CursorMove::Up);
+                        unicode_inspector_textarea.move_cursor(tui_textarea::CursorMove::Up);
 
                         continue;
                     }
                     // Handle Right, Up, and Down arrow keys similar to Left Arrow,
                     //  but for corresponding directions.
                     KeyCode::Down => {
+                        // Move the date converter's calendar by a week when it is shown.
+                        if app.current_tool == Tool::DateConverter
+                            && app.date_converter.calendar_moves_date()
+                        {
+                            move_date_calendar(&mut app, date_converter_textarea, 7, 0);
+                            continue;
+                        }
                         base64_converter_textarea.move_cursor(tui_textarea::CursorMove::Down);
+                        chmod_calculator_textarea.move_cursor(tui_textarea::CursorMove::Down);
                         color_converter_textarea.move_cursor(tui_textarea::CursorMove::Down);
+                        cron_explainer_textarea.move_cursor(tui_textarea::CursorMove::Down);
                         date_converter_textarea.move_cursor(tui_textarea::CursorMove::Down);
                         hash_generator_textarea.move_cursor(tui_textarea::CursorMove::Down);
+                        log_decoder_textarea.move_cursor(tui_textarea::CursorMove::Down);
+                        network_calculator_textarea.move_cursor(tui_textarea::CursorMove::Down);
                         number_base_converter_textarea.move_cursor(tui_textarea::CursorMove::Down);
                         qr_code_generator_textarea.move_cursor(tui_textarea::CursorMove::Down);
+                        unicode_inspector_textarea.move_cursor(tui_textarea::CursorMove::Down);
 
                         continue;
                     }
@@ -233,33 +387,59 @@ pub fn run_app<B: Backend>(
                     // Handle Enter key to insert a newline character.
                     KeyCode::Enter => {
                         base64_converter_textarea.insert_newline();
+                        chmod_calculator_textarea.insert_newline();
                         color_converter_textarea.insert_newline();
+                        cron_explainer_textarea.insert_newline();
                         date_converter_textarea.insert_newline();
                         hash_generator_textarea.insert_newline();
+                        log_decoder_textarea.insert_newline();
+                        network_calculator_textarea.insert_newline();
                         number_base_converter_textarea.insert_newline();
                         qr_code_generator_textarea.insert_newline();
+                        unicode_inspector_textarea.insert_newline();
                         continue;
                     }
 
                     // Handle Backspace key to delete the previous character.
                     KeyCode::Backspace => {
                         base64_converter_textarea.delete_char();
+                        chmod_calculator_textarea.delete_char();
                         color_converter_textarea.delete_char();
+                        cron_explainer_textarea.delete_char();
                         date_converter_textarea.delete_char();
                         hash_generator_textarea.delete_char();
+                        log_decoder_textarea.delete_char();
+                        network_calculator_textarea.delete_char();
                         number_base_converter_textarea.delete_char();
                         qr_code_generator_textarea.delete_char();
+                        unicode_inspector_textarea.delete_char();
+                        continue;
+                    }
+
+                    // Handle Page Up and Page Down keys to move the date converter's calendar by a month.
+                    KeyCode::PageUp | KeyCode::PageDown => {
+                        if app.current_tool == Tool::DateConverter
+                            && app.date_converter.calendar_moves_date()
+                        {
+                            let months = if key.code == KeyCode::PageUp { -1 } else { 1 };
+                            move_date_calendar(&mut app, date_converter_textarea, 0, months);
+                        }
                         continue;
                     }
 
                     // Handle Delete key to delete the next character.
                     KeyCode::Delete => {
                         base64_converter_textarea.delete_next_char();
+                        chmod_calculator_textarea.delete_next_char();
                         color_converter_textarea.delete_next_char();
+                        cron_explainer_textarea.delete_next_char();
                         date_converter_textarea.delete_next_char();
                         hash_generator_textarea.delete_next_char();
+                        log_decoder_textarea.delete_next_char();
+                        network_calculator_textarea.delete_next_char();
                         number_base_converter_textarea.delete_next_char();
                         qr_code_generator_textarea.insert_newline();
+                        unicode_inspector_textarea.delete_next_char();
                         continue;
                     }
                     _ => continue, // Continue loop if input is not recognized.
@@ -325,6 +505,65 @@ pub fn run_app<B: Backend>(
                             }
                         }
 
+                        //  Handles functionality for Chmod Calculator.
+                        Tool::ChmodCalculator => {
+                            // Only insert characters if ALT and CTRL are not pressed, this
+                            // prevents inserting of characters in the text area that shortcuts
+                            // for eg. if Alt + e is pressed it will not capture character "e"
+                            // and add it on the Text area/Input Field.
+                            if !key.modifiers.contains(KeyModifiers::ALT)
+                                && !key.modifiers.contains(KeyModifiers::CONTROL)
+                            {
+                                chmod_calculator_textarea.insert_char(c);
+                            }
+
+                            // Permission grid bits toggled by Alt + 1-9, Alt + s, Alt + g and Alt + t.
+                            let grid_bit = match c {
+                                '1' => Some(0o400),
+                                '2' => Some(0o200),
+                                '3' => Some(0o100),
+                                '4' => Some(0o040),
+                                '5' => Some(0o020),
+                                '6' => Some(0o010),
+                                '7' => Some(0o004),
+                                '8' => Some(0o002),
+                                '9' => Some(0o001),
+                                's' => Some(chmod_calculator::SETUID),
+                                'g' => Some(chmod_calculator::SETGID),
+                                't' => Some(chmod_calculator::STICKY),
+                                _ => None,
+                            };
+
+                            // Shortcut Key (Alt + x) to export the permissions.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'x' {
+                                match app.chmod_calculator.write_to_file() {
+                                    Ok(_) => {
+                                        app.chmod_calculator.tools_export_message = Some(
+                                            "Successfully exported to export/chmod.txt".to_string(),
+                                        );
+                                    }
+                                    Err(err) => {
+                                        app.chmod_calculator.tools_export_message =
+                                            Some(format!("Failed to export: {}", err));
+                                    }
+                                }
+                            } else if let (true, Some(bit)) =
+                                (key.modifiers.contains(KeyModifiers::ALT), grid_bit)
+                            {
+                                // Toggle the bit and show the resulting octal mode in the input.
+                                app.chmod_calculator.toggle_bit(bit);
+                                *chmod_calculator_textarea =
+                                    TextArea::from([app.chmod_calculator.octal.clone()]);
+                                chmod_calculator_textarea.move_cursor(CursorMove::End);
+                                app.chmod_calculator.input = app.chmod_calculator.octal.clone();
+                            } else if !key.modifiers.contains(KeyModifiers::ALT) {
+                                // Starts conversion automatically when Text area has input.
+                                app.chmod_calculator.input =
+                                    chmod_calculator_textarea.lines().join("\n");
+                                app.chmod_calculator.convert_all();
+                            }
+                        }
+
                         Tool::ColorConverter => {
                             // Only insert characters if ALT and CTRL are not pressed, this
                             // prevents inserting of characters in the text area that shortcuts
@@ -336,8 +575,95 @@ pub fn run_app<B: Backend>(
                                 color_converter_textarea.insert_char(c);
                             }
 
-                            // Shortcut Key (Alt + x) to export the input.
-                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'x' {
+                            // Shortcut Key (Alt + m) to switch modes.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'm' {
+                                app.color_converter.cycle_mode();
+                                app.color_converter.convert_all();
+                            }
+
+                            // Shortcut Key (Alt + f) to cycle the input format.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'f' {
+                                app.color_converter.cycle_format();
+                                app.color_converter.convert_all();
+                            }
+
+                            // Shortcut Key (Alt + o) to cycle the palette export format.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'o' {
+                                app.color_converter.cycle_palette_format();
+                            }
+
+                            // Shortcut Keys (Alt + s / Alt + , / Alt + . / Alt + i / Alt + d) to
+                            // change the color space, ratio and gradient steps colors are mixed with,
+                            // or the number of colors extracted from an image in image mode.
+                            if key.modifiers.contains(KeyModifiers::ALT) {
+                                let image_mode = app.color_converter.mode == ColorMode::Image;
+                                match c {
+                                    's' => app.color_converter.cycle_mix_space(),
+                                    ',' => app.color_converter.decrease_mix_ratio(),
+                                    '.' => app.color_converter.increase_mix_ratio(),
+                                    'i' if image_mode => {
+                                        app.color_converter.increase_image_color_count();
+                                        app.color_converter.extract_image_colors();
+                                    }
+                                    'd' if image_mode => {
+                                        app.color_converter.decrease_image_color_count();
+                                        app.color_converter.extract_image_colors();
+                                    }
+                                    'i' => app.color_converter.increase_gradient_steps(),
+                                    'd' => app.color_converter.decrease_gradient_steps(),
+                                    _ => {}
+                                }
+                                app.color_converter.mix_colors();
+                            }
+
+                            // Shortcut Key (Alt + x) to export the input, or the palette in palette mode.
+                            if key.modifiers.contains(KeyModifiers::ALT)
+                                && c == 'x'
+                                && app.color_converter.mode == ColorMode::Palette
+                            {
+                                match app.color_converter.export_palette() {
+                                    Ok(path) => {
+                                        app.color_converter.tools_export_message =
+                                            Some(format!("Successfully exported to {}", path));
+                                    }
+                                    Err(err) => {
+                                        app.color_converter.tools_export_message =
+                                            Some(format!("Failed to export: {}", err));
+                                    }
+                                }
+                            } else if key.modifiers.contains(KeyModifiers::ALT)
+                                && c == 'x'
+                                && app.color_converter.mode == ColorMode::Mix
+                            {
+                                match app.color_converter.export_gradient() {
+                                    Ok(_) => {
+                                        app.color_converter.tools_export_message = Some(
+                                            "Successfully exported to export/gradient.txt"
+                                                .to_string(),
+                                        );
+                                    }
+                                    Err(err) => {
+                                        app.color_converter.tools_export_message =
+                                            Some(format!("Failed to export: {}", err));
+                                    }
+                                }
+                            } else if key.modifiers.contains(KeyModifiers::ALT)
+                                && c == 'x'
+                                && app.color_converter.mode == ColorMode::Image
+                            {
+                                match app.color_converter.export_image_colors() {
+                                    Ok(_) => {
+                                        app.color_converter.tools_export_message = Some(
+                                            "Successfully exported to export/image_colors.txt"
+                                                .to_string(),
+                                        );
+                                    }
+                                    Err(err) => {
+                                        app.color_converter.tools_export_message =
+                                            Some(format!("Failed to export: {}", err));
+                                    }
+                                }
+                            } else if key.modifiers.contains(KeyModifiers::ALT) && c == 'x' {
                                 match app.color_converter.export_color_codes() {
                                     Ok(_) => {
                                         app.color_converter.tools_export_message = Some(
@@ -360,6 +686,49 @@ pub fn run_app<B: Backend>(
                             }
                         }
 
+                        //  Handles functionality for Cron Explainer.
+                        Tool::CronExplainer => {
+                            // Only insert characters if ALT and CTRL are not pressed, this
+                            // prevents inserting of characters in the text area that shortcuts
+                            // for eg. if Alt + e is pressed it will not capture character "e"
+                            // and add it on the Text area/Input Field.
+                            if !key.modifiers.contains(KeyModifiers::ALT)
+                                && !key.modifiers.contains(KeyModifiers::CONTROL)
+                            {
+                                cron_explainer_textarea.insert_char(c);
+                            }
+
+                            // Shortcut Key (Alt + x) to export the explanation and run times.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'x' {
+                                match app.cron_explainer.write_to_file() {
+                                    Ok(_) => {
+                                        app.cron_explainer.tools_export_message = Some(
+                                            "Successfully exported to export/cron.txt".to_string(),
+                                        );
+                                    }
+                                    Err(err) => {
+                                        app.cron_explainer.tools_export_message =
+                                            Some(format!("Failed to export: {}", err));
+                                    }
+                                }
+                            } else {
+                                // Shortcut Keys (Alt + i / Alt + d / Alt + z) to change the number
+                                // of run times and the time zone they are calculated in.
+                                if key.modifiers.contains(KeyModifiers::ALT) && c == 'i' {
+                                    app.cron_explainer.increase_run_count();
+                                } else if key.modifiers.contains(KeyModifiers::ALT) && c == 'd' {
+                                    app.cron_explainer.decrease_run_count();
+                                } else if key.modifiers.contains(KeyModifiers::ALT) && c == 'z' {
+                                    app.cron_explainer.toggle_time_zone();
+                                }
+
+                                // Explain the expression automatically, when input/Text area have characters.
+                                app.cron_explainer.input =
+                                    cron_explainer_textarea.lines().join("\n");
+                                app.cron_explainer.explain();
+                            }
+                        }
+
                         //  Handles functionality for Date Converter
                         Tool::DateConverter => {
                             // Only insert characters if ALT and CTRL are not pressed, this
@@ -372,10 +741,74 @@ pub fn run_app<B: Backend>(
                                 date_converter_textarea.insert_char(c);
                             }
 
-                            // Automatically insert newlines for better readability in date output (every 50 characters).
-                            if date_converter_textarea.lines().join("\n").len() % 50 == 0 {
-                                date_converter_textarea.insert_newline();
+                            // Shortcut Key (Alt + p) to cycle the unit timestamps are counted in.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'p' {
+                                app.date_converter.cycle_precision();
+                            }
+
+                            // Shortcut Key (Alt + o) to switch between day first and month first dates.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'o' {
+                                app.date_converter.toggle_date_order();
+                            }
+
+                            // Shortcut Key (Alt + m) to switch between converting and date arithmetic.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'm' {
+                                app.date_converter.toggle_mode();
+                            }
+
+                            // Shortcut Keys (Alt + s / Alt + r) to save the custom format of the
+                            // FORMAT= line, and to remove the last saved one.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 's' {
+                                app.date_converter.save_format();
+                            } else if key.modifiers.contains(KeyModifiers::ALT) && c == 'r' {
+                                app.date_converter.remove_format();
+                            }
+
+                            // Shortcut Key (Alt + c) to show or hide the calendar.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'c' {
+                                app.date_converter.toggle_calendar();
+                            }
+
+                            // Shortcut Key (Alt + w) to show or hide the world clock.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'w' {
+                                app.date_converter.toggle_clock();
+                            }
+
+                            // Shortcut Key (Alt + h) to show or hide the strftime specifiers.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'h' {
+                                app.date_converter.toggle_format_reference();
+                            }
+
+                            // Shortcut Keys (Alt + x / Alt + j) to export the conversions as text or JSON.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'x' {
+                                match app.date_converter.write_to_file() {
+                                    Ok(_) => {
+                                        app.date_converter.tools_export_message = Some(
+                                            "Successfully exported to export/date.txt".to_string(),
+                                        );
+                                    }
+                                    Err(err) => {
+                                        app.date_converter.tools_export_message =
+                                            Some(format!("Failed to export: {}", err));
+                                    }
+                                }
+                            } else if key.modifiers.contains(KeyModifiers::ALT) && c == 'j' {
+                                match app.date_converter.export_json() {
+                                    Ok(_) => {
+                                        app.date_converter.tools_export_message = Some(
+                                            "Successfully exported to export/date.json".to_string(),
+                                        );
+                                    }
+                                    Err(err) => {
+                                        app.date_converter.tools_export_message =
+                                            Some(format!("Failed to export: {}", err));
+                                    }
+                                }
+                            } else {
+                                // Clear export message when new input is received
+                                app.date_converter.tools_export_message = None;
                             }
+
                             // Update the date converter's input with the text from the TextArea.
                             app.date_converter.input = date_converter_textarea.lines().join("\n");
                             // Automatically Convert the input date to all supported formats.
@@ -384,6 +817,11 @@ pub fn run_app<B: Backend>(
 
                         //  Handles functionality for Hash Generator
                         Tool::HashGenerator => {
+                            hash_generator_key(&mut app, hash_generator_textarea, c, key.modifiers);
+                        }
+
+                        //  Handles functionality for Log Decoder.
+                        Tool::LogDecoder => {
                             // Only insert characters if ALT and CTRL are not pressed, this
                             // prevents inserting of characters in the text area that shortcuts
                             // for eg. if Alt + e is pressed it will not capture character "e"
@@ -391,31 +829,72 @@ pub fn run_app<B: Backend>(
                             if !key.modifiers.contains(KeyModifiers::ALT)
                                 && !key.modifiers.contains(KeyModifiers::CONTROL)
                             {
-                                hash_generator_textarea.insert_char(c);
+                                log_decoder_textarea.insert_char(c);
+                            }
+
+                            // Shortcut Key (Alt + x) to export the annotated log.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'x' {
+                                match app.log_decoder.write_to_file() {
+                                    Ok(_) => {
+                                        app.log_decoder.tools_export_message = Some(
+                                            "Successfully exported to export/log.txt".to_string(),
+                                        );
+                                    }
+                                    Err(err) => {
+                                        app.log_decoder.tools_export_message =
+                                            Some(format!("Failed to export: {}", err));
+                                    }
+                                }
+                            } else {
+                                // Shortcut Key (Alt + z) to switch the time zone of the annotations.
+                                if key.modifiers.contains(KeyModifiers::ALT) && c == 'z' {
+                                    app.log_decoder.toggle_time_zone();
+                                }
+
+                                // Decode the log automatically, when input/Text area have characters.
+                                app.log_decoder.input = log_decoder_textarea.lines().join("\n");
+                                app.log_decoder.decode();
                             }
+                        }
 
-                            //  Automatically insert newlines for better readability in hash output (every 62 characters).
-                            if hash_generator_textarea.lines().join("\n").len() % 62 == 0 {
-                                hash_generator_textarea.insert_newline();
+                        //  Handles functionality for Network Calculator.
+                        Tool::NetworkCalculator => {
+                            // Only insert characters if ALT and CTRL are not pressed, this
+                            // prevents inserting of characters in the text area that shortcuts
+                            // for eg. if Alt + e is pressed it will not capture character "e"
+                            // and add it on the Text area/Input Field.
+                            if !key.modifiers.contains(KeyModifiers::ALT)
+                                && !key.modifiers.contains(KeyModifiers::CONTROL)
+                            {
+                                network_calculator_textarea.insert_char(c);
                             }
 
-                            // Shortcut Key (Alt + x) to Export generated hashes.
+                            // Shortcut Key (Alt + x) to export the network details and subnets.
                             if key.modifiers.contains(KeyModifiers::ALT) && c == 'x' {
-                                match app.hash_generator.write_to_file() {
+                                match app.network_calculator.write_to_file() {
                                     Ok(_) => {
-                                        app.hash_generator.tools_export_message = Some(
-                                            "Successfully exported to export/hash.txt".to_string(),
+                                        app.network_calculator.tools_export_message = Some(
+                                            "Successfully exported to export/network.txt"
+                                                .to_string(),
                                         );
                                     }
                                     Err(err) => {
-                                        app.hash_generator.tools_export_message =
+                                        app.network_calculator.tools_export_message =
                                             Some(format!("Failed to export: {}", err));
                                     }
                                 }
-                            // If ALT is not pressed, update the input string and calculate hashes.
-                            } else if !key.modifiers.contains(KeyModifiers::ALT) {
-                                let new_input = hash_generator_textarea.lines().join("\n");
-                                app.hash_generator.update_input(&new_input);
+                            } else {
+                                // Shortcut Keys (Alt + i / Alt + d) to change the number of subnets.
+                                if key.modifiers.contains(KeyModifiers::ALT) && c == 'i' {
+                                    app.network_calculator.increase_subnet_count();
+                                } else if key.modifiers.contains(KeyModifiers::ALT) && c == 'd' {
+                                    app.network_calculator.decrease_subnet_count();
+                                }
+
+                                // Calculate the network automatically, when input/Text area have characters.
+                                app.network_calculator.input =
+                                    network_calculator_textarea.lines().join("\n");
+                                app.network_calculator.calculate();
                             }
                         }
 
@@ -565,6 +1044,45 @@ pub fn run_app<B: Backend>(
                             }
                         }
 
+                        //  Handles functionality for Unicode Inspector.
+                        Tool::UnicodeInspector => {
+                            // Only insert characters if ALT and CTRL are not pressed, this
+                            // prevents inserting of characters in the text area that shortcuts
+                            // for eg. if Alt + e is pressed it will not capture character "e"
+                            // and add it on the Text area/Input Field.
+                            if !key.modifiers.contains(KeyModifiers::ALT)
+                                && !key.modifiers.contains(KeyModifiers::CONTROL)
+                            {
+                                unicode_inspector_textarea.insert_char(c);
+                            }
+
+                            // Shortcut Key (Alt + x) to export the character table.
+                            if key.modifiers.contains(KeyModifiers::ALT) && c == 'x' {
+                                match app.unicode_inspector.write_to_file() {
+                                    Ok(_) => {
+                                        app.unicode_inspector.tools_export_message = Some(
+                                            "Successfully exported to export/unicode.txt"
+                                                .to_string(),
+                                        );
+                                    }
+                                    Err(err) => {
+                                        app.unicode_inspector.tools_export_message =
+                                            Some(format!("Failed to export: {}", err));
+                                    }
+                                }
+                            } else {
+                                // Shortcut Key (Alt + l) to switch between inspecting and lookup.
+                                if key.modifiers.contains(KeyModifiers::ALT) && c == 'l' {
+                                    app.unicode_inspector.toggle_mode();
+                                }
+
+                                // Inspect the input automatically, when input/Text area have characters.
+                                app.unicode_inspector.input =
+                                    unicode_inspector_textarea.lines().join("\n");
+                                app.unicode_inspector.inspect();
+                            }
+                        }
+
                         //  Handles functionality for UUID generator.
                         Tool::UuidGenerator => match c {
                             // Shortcut Keys, Character mapped to each respective functionality i.e
@@ -613,17 +1131,73 @@ pub fn run_app<B: Backend>(
     }
 }
 
+// Moves the date of the date converter from its calendar, and shows the new date in the input.
+fn move_date_calendar(app: &mut App, textarea: &mut TextArea, days: i64, months: i32) {
+    if app.date_converter.move_calendar(days, months) {
+        *textarea = TextArea::from(app.date_converter.input.lines());
+        textarea.move_cursor(CursorMove::Bottom);
+        textarea.move_cursor(CursorMove::End);
+        app.date_converter.tools_export_message = None;
+    }
+}
+
+// Handles a character typed into the hash generator, either as input or as a shortcut.
+fn hash_generator_key(app: &mut App, textarea: &mut TextArea, c: char, modifiers: KeyModifiers) {
+    // Only insert characters if ALT and CTRL are not pressed, this
+    // prevents inserting of characters in the text area that shortcuts
+    // for eg. if Alt + e is pressed it will not capture character "e"
+    // and add it on the Text area/Input Field.
+    if !modifiers.contains(KeyModifiers::ALT) && !modifiers.contains(KeyModifiers::CONTROL) {
+        textarea.insert_char(c);
+
+        //  Automatically insert newlines for better readability in hash output (every 62 characters).
+        if textarea.lines().join("\n").len() % 62 == 0 {
+            textarea.insert_newline();
+        }
+    }
+
+    // Shortcut Key (Alt + x) to Export generated hashes.
+    if modifiers.contains(KeyModifiers::ALT) && c == 'x' {
+        match app.hash_generator.write_to_file() {
+            Ok(_) => {
+                app.hash_generator.tools_export_message =
+                    Some("Successfully exported to export/hash.txt".to_string());
+            }
+            Err(err) => {
+                app.hash_generator.tools_export_message =
+                    Some(format!("Failed to export: {}", err));
+            }
+        }
+    // Shortcut Keys (Alt + 1-9 / Alt + 0) to show or hide an algorithm.
+    } else if let (true, Some(algorithm)) = (
+        modifiers.contains(KeyModifiers::ALT),
+        HashAlgorithm::from_key(c),
+    ) {
+        app.hash_generator.toggle_algorithm(algorithm);
+    // If ALT is not pressed, update the input string and calculate hashes.
+    } else if !modifiers.contains(KeyModifiers::ALT) {
+        let new_input = textarea.lines().join("\n");
+        app.hash_generator.update_input(&new_input);
+    }
+}
+
 // Handles the user interface based on the selected tool and app state.
 // Takes the frame, application state, and the input text areas for different tools as arguments.
+#[allow(clippy::too_many_arguments)]
 fn ui(
     f: &mut Frame,
     app: &mut App,
     base64_converter_textarea: &mut TextArea,
+    chmod_calculator_textarea: &mut TextArea,
     color_converter_textarea: &mut TextArea,
+    cron_explainer_textarea: &mut TextArea,
     date_converter_textarea: &mut TextArea,
     hash_generator_textarea: &mut TextArea,
+    log_decoder_textarea: &mut TextArea,
+    network_calculator_textarea: &mut TextArea,
     number_base_converter_textarea: &mut TextArea,
     qr_code_generator_textarea: &mut TextArea,
+    unicode_inspector_textarea: &mut TextArea,
 ) {
     // Split the frame into three chunks: title, tabs, and tool content area.
     let chunks = Layout::default()
@@ -657,12 +1231,17 @@ fn ui(
     // Render the tabs for different tools.
     let tabs = Tabs::new(vec![
         Span::raw("Base64 Encoder"),
+        Span::raw("Chmod Calculator"),
         Span::raw("Color Code Converter"),
+        Span::raw("Cron Explainer"),
         Span::raw("Date Converter"),
         Span::raw("Hash Generator"),
+        Span::raw("Log Decoder"),
+        Span::raw("Network Calculator"),
         Span::raw("Number Base Converter"),
         Span::raw("Password Generator"),
         Span::raw("QR Code Generator"),
+        Span::raw("Unicode Inspector"),
         Span::raw("UUID Generator"),
     ])
     .block(
@@ -679,13 +1258,18 @@ fn ui(
     // Select the currently active tool.
     .select(match app.current_tool {
         Tool::Base64Encoder => 0,
-        Tool::ColorConverter => 1,
-        Tool::DateConverter => 2,
-        Tool::HashGenerator => 3,
-        Tool::NumberBaseConverter => 4,
-        Tool::PasswordGenerator => 5,
-        Tool::QRCodeGenerator => 6,
-        Tool::UuidGenerator => 7,
+        Tool::ChmodCalculator => 1,
+        Tool::ColorConverter => 2,
+        Tool::CronExplainer => 3,
+        Tool::DateConverter => 4,
+        Tool::HashGenerator => 5,
+        Tool::LogDecoder => 6,
+        Tool::NetworkCalculator => 7,
+        Tool::NumberBaseConverter => 8,
+        Tool::PasswordGenerator => 9,
+        Tool::QRCodeGenerator => 10,
+        Tool::UnicodeInspector => 11,
+        Tool::UuidGenerator => 12,
     })
     .divider("|")
     .padding(" ", " ");
@@ -697,11 +1281,19 @@ fn ui(
     // Render the UI based on the selected tool.
     match app.current_tool {
         Tool::Base64Encoder => base64_encoder(f, app, tool_content_area, base64_converter_textarea),
+        Tool::ChmodCalculator => {
+            chmod_calculator(f, app, tool_content_area, chmod_calculator_textarea)
+        }
         Tool::ColorConverter => {
             color_code_converter(f, app, tool_content_area, color_converter_textarea)
         }
+        Tool::CronExplainer => cron_explainer(f, app, tool_content_area, cron_explainer_textarea),
         Tool::DateConverter => date_converter(f, app, tool_content_area, date_converter_textarea),
         Tool::HashGenerator => hash_generator(f, app, tool_content_area, hash_generator_textarea),
+        Tool::LogDecoder => log_decoder(f, app, tool_content_area, log_decoder_textarea),
+        Tool::NetworkCalculator => {
+            network_calculator(f, app, tool_content_area, network_calculator_textarea)
+        }
         Tool::NumberBaseConverter => {
             number_base_converter(f, app, tool_content_area, number_base_converter_textarea)
         }
@@ -709,6 +1301,9 @@ fn ui(
         Tool::QRCodeGenerator => {
             qr_code_generator(f, app, tool_content_area, qr_code_generator_textarea)
         }
+        Tool::UnicodeInspector => {
+            unicode_inspector(f, app, tool_content_area, unicode_inspector_textarea)
+        }
         Tool::UuidGenerator => uuid_generator(f, app, tool_content_area),
     }
 }
@@ -907,12 +1502,12 @@ fn base64_encoder(
     f.render_widget(decoded, encoded_decoded_chunks[1]);
 }
 
-// Handles the UI for color code converter.
-fn color_code_converter(
+// Handles the UI for chmod calculator.
+fn chmod_calculator(
     f: &mut Frame,
     app: &mut App,
     area: Rect,
-    color_converter_textarea: &mut TextArea,
+    chmod_calculator_textarea: &mut TextArea,
 ) {
     // Split the area into two chunks: input/guide area and conversion results area.
     let chunks = Layout::default()
@@ -923,13 +1518,13 @@ fn color_code_converter(
     // Split the input/guide area into two chunks: input area and guide/status area.
     let input_guide_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .constraints([Constraint::Percentage(20), Constraint::Percentage(80)])
         .split(chunks[0]);
 
     // Style the input text area.
-    color_converter_textarea.set_block(
+    chmod_calculator_textarea.set_block(
         Block::default()
-            .title(" Enter Color Code ")
+            .title(" Enter Mode or Expression ")
             .title_style(Style::default().fg(Color::Yellow).bold())
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Yellow))
@@ -937,10 +1532,10 @@ fn color_code_converter(
             .padding(Padding::new(1, 1, 0, 0)),
     );
 
-    color_converter_textarea.set_style(Style::default().bold());
+    chmod_calculator_textarea.set_style(Style::default().bold());
 
     // Render the input text area.
-    f.render_widget(&*color_converter_textarea, input_guide_chunks[0]);
+    f.render_widget(&*chmod_calculator_textarea, input_guide_chunks[0]);
 
     // Split the guide/status area into two chunks: guide area and status area.
     let guide_status_chunks = Layout::default()
@@ -948,7 +1543,7 @@ fn color_code_converter(
         .constraints([Constraint::Percentage(80), Constraint::Percentage(20)])
         .split(input_guide_chunks[1]);
 
-    // Create a guide text with shortcut keys and instructions.
+    // Create a guide text with shortcut keys and examples.
     let guide_text = vec![
         Line::from(vec![
             Span::styled(
@@ -958,7 +1553,7 @@ fn color_code_converter(
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                "        Quit",
+                "          Quit",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -972,7 +1567,7 @@ fn color_code_converter(
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                "        Switch Tools",
+                "          Switch Tools",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -980,77 +1575,77 @@ fn color_code_converter(
         ]),
         Line::from(vec![
             Span::styled(
-                "Alt + x",
+                "Alt + 1-9",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                "    Export Colors",
+                "    Toggle Permission in Grid",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
-        Line::from(vec![Span::raw("")]),
         Line::from(vec![
             Span::styled(
-                "Exported File Path:",
+                "Alt + s/g/t",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " export/color_codes.txt",
+                "  Toggle Setuid/Setgid/Sticky",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
-        Line::from(vec![Span::raw("")]),
-        Line::from(vec![Span::styled(
-            "Examples:",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )]),
         Line::from(vec![
             Span::styled(
-                "CMYK:",
+                "Alt + x",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " 0, 26, 99, 1 or 0%, 26%, 99%, 1%",
+                "      Export Permissions",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
+        Line::from(vec![Span::raw("")]),
         Line::from(vec![
             Span::styled(
-                "HEX:",
+                "Exported File Path:",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " #fcba03 or #FCBA03 ",
+                " export/chmod.txt",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![Span::styled(
+            "Examples:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
         Line::from(vec![
             Span::styled(
-                "HSL:",
+                "Octal:",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " 44, 98, 50 or 44°, 98%, 50% ",
+                " 755, 0644 or 4755",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -1058,13 +1653,41 @@ fn color_code_converter(
         ]),
         Line::from(vec![
             Span::styled(
-                "RGB:",
+                "Symbolic:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " rwxr-xr-x or drwxrwxrwt",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Expression:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " u+x,go-w (applied to the current mode)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Base + Expression:",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " 252, 186, 3 ",
+                " 644 u+x,g=u",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -1077,7 +1700,7 @@ fn color_code_converter(
         .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Red))
         .block(
             Block::default()
-                .title(" Color Converter Help ")
+                .title(" Chmod Calculator Help ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 1, 0)),
@@ -1086,8 +1709,8 @@ fn color_code_converter(
     f.render_widget(guide, guide_status_chunks[0]);
 
     // Create a status block to display messages or errors.
-    let status_text = if let Some(message) = &app.color_converter.tools_export_message {
-        format!("{}", message)
+    let status_text = if let Some(message) = &app.chmod_calculator.tools_export_message {
+        message.to_string()
     } else {
         "".to_string()
     };
@@ -1109,37 +1732,92 @@ fn color_code_converter(
         .wrap(Wrap { trim: true });
     f.render_widget(status_block, guide_status_chunks[1]);
 
-    // Split the conversion results area into four chunks for displaying CMYK, HEX, HSL, and RGB.
+    // Split the conversion results area into the permission grid and the representations.
     let conversion_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
+            Constraint::Percentage(35),
+            Constraint::Percentage(20),
+            Constraint::Percentage(45),
         ])
         .split(chunks[1]);
 
-    // Render the CMYK value.
-    let cmyk = Paragraph::new(app.color_converter.cmyk.clone())
-        .style(
+    // Build a grid cell showing whether a bit is set, with the key that toggles it.
+    let mode = app.chmod_calculator.mode;
+    let cell = |bit: u32, key: &str, label: &str| {
+        let (mark, color) = if mode & bit != 0 {
+            ("[x]", Color::Green)
+        } else {
+            ("[ ]", Color::DarkGray)
+        };
+        vec![
+            Span::styled(
+                format!("{} ", mark),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{:<10}", format!("{} {}", key, label)),
+                Style::default().fg(Color::White),
+            ),
+        ]
+    };
+
+    // Render the permission grid.
+    let mut grid_text = vec![Line::from(vec![Span::styled(
+        "              Read           Write          Execute",
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )])];
+    for (name, shift, first_key) in [
+        ("Owner (u)", 6, 1),
+        ("Group (g)", 3, 4),
+        ("Other (o)", 0, 7),
+    ] {
+        let mut spans = vec![Span::styled(
+            format!("{:<14}", name),
             Style::default()
-                .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
-        )
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        )];
+        for (offset, bit) in [0o4, 0o2, 0o1].iter().enumerate() {
+            spans.extend(cell(bit << shift, &(first_key + offset).to_string(), ""));
+            spans.push(Span::raw("    "));
+        }
+        grid_text.push(Line::from(spans));
+    }
+    let mut special_spans = vec![Span::styled(
+        format!("{:<14}", "Special"),
+        Style::default()
+            .fg(Color::Blue)
+            .add_modifier(Modifier::BOLD),
+    )];
+    special_spans.extend(cell(chmod_calculator::SETUID, "s", "setuid"));
+    special_spans.push(Span::raw(" "));
+    special_spans.extend(cell(chmod_calculator::SETGID, "g", "setgid"));
+    special_spans.push(Span::raw(" "));
+    special_spans.extend(cell(chmod_calculator::STICKY, "t", "sticky"));
+    grid_text.push(Line::from(special_spans));
+
+    let grid = Paragraph::new(grid_text)
         .block(
             Block::default()
-                .title(" CMYK Color Code ")
+                .title(" Permission Grid (Alt + key) ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
-        .wrap(Wrap { trim: true })
-        .scroll((0, 0));
-    f.render_widget(cmyk, conversion_chunks[0]);
+        .wrap(Wrap { trim: false });
+    f.render_widget(grid, conversion_chunks[0]);
 
-    // Render the HEX value.
-    let hex = Paragraph::new(app.color_converter.hex.clone())
+    // Split the middle row into octal and symbolic representations.
+    let octal_symbolic_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(conversion_chunks[1]);
+
+    // Render the octal mode.
+    let octal = Paragraph::new(app.chmod_calculator.octal.as_str())
         .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
@@ -1147,17 +1825,16 @@ fn color_code_converter(
         )
         .block(
             Block::default()
-                .title(" HEX Color Code ")
+                .title(" Octal ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
-        .wrap(Wrap { trim: true })
-        .scroll((0, 0));
-    f.render_widget(hex, conversion_chunks[1]);
+        .wrap(Wrap { trim: true });
+    f.render_widget(octal, octal_symbolic_chunks[0]);
 
-    // Render the HSL value.
-    let hsl = Paragraph::new(app.color_converter.hsl.clone())
+    // Render the symbolic mode.
+    let symbolic = Paragraph::new(app.chmod_calculator.ls_format.as_str())
         .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
@@ -1165,57 +1842,103 @@ fn color_code_converter(
         )
         .block(
             Block::default()
-                .title(" HSL Color Code ")
+                .title(" Symbolic (ls -l) ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
-        .wrap(Wrap { trim: true })
-        .scroll((0, 0));
-    f.render_widget(hsl, conversion_chunks[2]);
+        .wrap(Wrap { trim: true });
+    f.render_widget(symbolic, octal_symbolic_chunks[1]);
 
-    // Render the RGB value.
-    let rgb = Paragraph::new(app.color_converter.rgb.clone())
+    // Split the bottom row into the description and the equivalent commands.
+    let description_command_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(conversion_chunks[2]);
+
+    // Render the plain English description.
+    let description = Paragraph::new(app.chmod_calculator.description.as_str())
         .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
+                .fg(Color::Cyan),
         )
         .block(
             Block::default()
-                .title(" RGB Color Code ")
+                .title(" Permissions ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
-        .wrap(Wrap { trim: true })
-        .scroll((0, 0));
-    f.render_widget(rgb, conversion_chunks[3]);
+        .wrap(Wrap { trim: true });
+    f.render_widget(description, description_command_chunks[0]);
+
+    // Render the interpretation of the input and the equivalent commands.
+    let commands = Paragraph::new(format!(
+        "{}\n\n{}",
+        app.chmod_calculator.input_format, app.chmod_calculator.chmod_commands
+    ))
+    .style(
+        Style::default()
+            .add_modifier(Modifier::BOLD)
+            .fg(Color::Green),
+    )
+    .block(
+        Block::default()
+            .title(" Commands ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .padding(Padding::new(1, 1, 0, 0)),
+    )
+    .wrap(Wrap { trim: true });
+    f.render_widget(commands, description_command_chunks[1]);
 }
 
-// Handles the UI for date converter.
-fn date_converter(
+// Handles the UI for color code converter.
+fn color_code_converter(
     f: &mut Frame,
     app: &mut App,
     area: Rect,
-    date_converter_textarea: &mut TextArea,
+    color_converter_textarea: &mut TextArea,
 ) {
     // Split the area into two chunks: input/guide area and conversion results area.
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)].as_ref())
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
         .split(area);
 
-    // Split the input/guide area into two chunks: input area and guide area.
+    // Split the input/guide area into two chunks: input area and guide/status area.
     let input_guide_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
         .split(chunks[0]);
 
     // Style the input text area.
-    date_converter_textarea.set_block(
+    color_converter_textarea.set_block(
         Block::default()
-            .title(" Enter Date ")
+            .title(match app.color_converter.mode {
+                ColorMode::ColorBlindness => format!(
+                    " Enter Color, and Optionally a Background Color (Format: {}) ",
+                    app.color_converter.format.label()
+                ),
+                ColorMode::Palette => format!(
+                    " Enter Base Color (Format: {}) ",
+                    app.color_converter.format.label()
+                ),
+                ColorMode::Contrast => format!(
+                    " Enter Text Color, then Background Color (Format: {}) ",
+                    app.color_converter.format.label()
+                ),
+                ColorMode::Mix => format!(
+                    " Enter Two Colors, One per Line (Format: {}) ",
+                    app.color_converter.format.label()
+                ),
+                ColorMode::Image => " Enter the Path of an Image File ".to_string(),
+                _ => format!(
+                    " Enter Color Code (Format: {}) ",
+                    app.color_converter.format.label()
+                ),
+            })
             .title_style(Style::default().fg(Color::Yellow).bold())
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Yellow))
@@ -1223,12 +1946,18 @@ fn date_converter(
             .padding(Padding::new(1, 1, 0, 0)),
     );
 
-    date_converter_textarea.set_style(Style::default().bold());
+    color_converter_textarea.set_style(Style::default().bold());
 
     // Render the input text area.
-    f.render_widget(&*date_converter_textarea, input_guide_chunks[0]);
+    f.render_widget(&*color_converter_textarea, input_guide_chunks[0]);
 
-    // Create a guide text with supported formats, examples, and shortcut keys.
+    // Split the guide/status area into two chunks: guide area and status area.
+    let guide_status_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(80), Constraint::Percentage(20)])
+        .split(input_guide_chunks[1]);
+
+    // Create a guide text with shortcut keys and instructions.
     let guide_text = vec![
         Line::from(vec![
             Span::styled(
@@ -1238,7 +1967,7 @@ fn date_converter(
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                "    Quit",
+                "        Quit",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -1252,65 +1981,21 @@ fn date_converter(
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                "    Switch Tools",
+                "        Switch Tools",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
-        Line::from(vec![Span::raw("")]),
-        Line::from(vec![Span::styled(
-            "Supported Formats:",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(vec![Span::styled(
-            "YYYY-MM-DD H:M:S",
-            Style::default()
-                .fg(Color::Blue)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(vec![Span::styled(
-            "YYYY-MM-DDTH:M:S:z",
-            Style::default()
-                .fg(Color::Blue)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(vec![Span::styled(
-            "YYYY-MM-DD",
-            Style::default()
-                .fg(Color::Blue)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(vec![Span::styled(
-            "DD/MM/YYYY H:M:S",
-            Style::default()
-                .fg(Color::Blue)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(vec![Span::styled(
-            "DD/MM/YYYY  ",
-            Style::default()
-                .fg(Color::Blue)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(vec![Span::raw("")]),
-        Line::from(vec![Span::styled(
-            "Examples:",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )]),
         Line::from(vec![
             Span::styled(
-                "RFC 3339:",
+                "Alt + m",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " 2024-05-22T13:00:00Z",
+                format!("    Switch Mode ({})", app.color_converter.mode.label()),
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -1318,13 +2003,13 @@ fn date_converter(
         ]),
         Line::from(vec![
             Span::styled(
-                "RFC 2822:",
+                "Alt + f",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " Tue, 22 May 2022 13:00:00 +0100",
+                "    Cycle Input Format",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -1332,13 +2017,16 @@ fn date_converter(
         ]),
         Line::from(vec![
             Span::styled(
-                "ISO 8601:",
+                "Alt + o",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " 2024-05-22T13:00:00+01:00 or 20240522T130000+0100",
+                format!(
+                    "    Palette Export Format ({})",
+                    app.color_converter.palette_format.label()
+                ),
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -1346,13 +2034,16 @@ fn date_converter(
         ]),
         Line::from(vec![
             Span::styled(
-                "Unix Timestamp:",
+                "Alt + s",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " 1716382800",
+                format!(
+                    "    Mix Color Space ({})",
+                    app.color_converter.mix_space.label()
+                ),
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -1360,13 +2051,13 @@ fn date_converter(
         ]),
         Line::from(vec![
             Span::styled(
-                "Human Readable:",
+                "Alt + ,/.",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " Tuesday, March 1, 2022, 1:00:00 PM",
+                format!("  Mix Ratio ({}%)", app.color_converter.mix_ratio),
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -1374,201 +2065,3056 @@ fn date_converter(
         ]),
         Line::from(vec![
             Span::styled(
-                "Short Date:",
+                "Alt + i/d",
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " 05/22/2024 or 2024-03-22",
+                match app.color_converter.mode {
+                    ColorMode::Image => {
+                        format!("  Image Colors ({})", app.color_converter.image_color_count)
+                    }
+                    _ => format!("  Gradient Steps ({})", app.color_converter.gradient_steps),
+                },
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
-    ];
-
-    // Render the guide text.
-    let guide = Paragraph::new(guide_text)
-        .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Red))
-        .block(
-            Block::default()
-                .title(" Date Converter Help ")
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .padding(Padding::new(1, 1, 1, 0)),
-        )
-        .wrap(Wrap { trim: true });
-    f.render_widget(guide, input_guide_chunks[1]);
-
-    // Split the conversion results area into two chunks.
-    let converstion_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-        .split(chunks[1]);
-
-    // Split the first chunk of conversion results area further.
-    let converstion_chunks_first_split = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(converstion_chunks[0]);
-
-    // Split the top portion of the first chunk again.
-    let converstion_chunks_second_split = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(converstion_chunks_first_split[0]);
-
-    // Render the RFC 3339 conversion result.
-    let rfc3339_text = vec![Line::from(vec![Span::styled(
-        app.date_converter.rfc3339.to_string(),
-        Style::default().fg(Color::Green),
-    )])];
-    let rfc3339 = Paragraph::new(rfc3339_text)
-        .style(
+        Line::from(vec![
+            Span::styled(
+                "Alt + x",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Export Colors",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![
+            Span::styled(
+                "Exported File Path:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                match app.color_converter.mode {
+                    ColorMode::Palette => format!(" {}", app.color_converter.palette_format.path()),
+                    ColorMode::Mix => " export/gradient.txt".to_string(),
+                    ColorMode::Image => " export/image_colors.txt".to_string(),
+                    _ => " export/color_codes.txt".to_string(),
+                },
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![Span::styled(
+            "Examples:",
             Style::default()
-                .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
-        )
-        .block(
-            Block::default()
-                .title(" RFC 3339 Conversion ")
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .padding(Padding::new(1, 1, 0, 0)),
-        )
-        .wrap(Wrap { trim: true });
-    f.render_widget(rfc3339, converstion_chunks_second_split[0]);
-
-    // Render the RFC 2822 conversion result.
-    let rfc2822_text = vec![Line::from(vec![Span::styled(
-        app.date_converter.rfc2822.to_string(),
-        Style::default().fg(Color::Green),
-    )])];
-    let rfc2822 = Paragraph::new(rfc2822_text)
-        .style(
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![
+            Span::styled(
+                "CMYK:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 0, 26, 99, 1 or 0%, 26%, 99%, 1%",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "HEX:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " #fcba03, #fff or #FCBA0380 (with alpha)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "HSL:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 44°, 98%, 50% or hsl(44deg 98% 50%)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "RGB:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 252, 186, 3 or rgb(252 186 3)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Spaces:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " hsv(44 99% 99%), lab(80 16 81) or oklch(0.83 0.17 83)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Prefix:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " hsl: 44, 98, 50 (also rgb:, cmyk: and hex:)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Named:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " rebeccapurple or transparent",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Two Colors:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " #767676, then Enter and #ffffff on the second line",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alpha:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " rgb(252 186 3 / 50%) or hsla(44, 98%, 50%, 0.5)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+
+    // Render the guide text.
+    let guide = Paragraph::new(guide_text)
+        .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Red))
+        .block(
+            Block::default()
+                .title(" Color Converter Help ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 1, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(guide, guide_status_chunks[0]);
+
+    // Create a status block to display messages or errors.
+    let status_text = if let Some(message) = &app.color_converter.tools_export_message {
+        message.to_string()
+    } else {
+        "".to_string()
+    };
+
+    // Render the status block.
+    let status_block = Paragraph::new(status_text)
+        .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
+                .fg(Color::LightMagenta),
         )
         .block(
             Block::default()
-                .title(" RFC 2822 Conversion ")
+                .title(" Status ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
         .wrap(Wrap { trim: true });
-    f.render_widget(rfc2822, converstion_chunks_second_split[1]);
+    f.render_widget(status_block, guide_status_chunks[1]);
 
-    // Split the second chunk of the conversion results area.
-    let converstion_chunks_third_split = Layout::default()
+    // Render the results of the current mode.
+    match app.color_converter.mode {
+        ColorMode::Convert => color_conversions(f, app, chunks[1]),
+        ColorMode::Contrast => color_contrast(f, app, chunks[1]),
+        ColorMode::Palette => color_palette(f, app, chunks[1]),
+        ColorMode::ColorBlindness => color_blindness(f, app, chunks[1]),
+        ColorMode::Mix => color_mix(f, app, chunks[1]),
+        ColorMode::Image => color_image(f, app, chunks[1]),
+    }
+}
+
+// Renders the color codes of the converted color, with a preview.
+fn color_conversions(f: &mut Frame, app: &App, area: Rect) {
+    // Split the conversion results area into a preview and the color codes below it.
+    let preview_codes_chunks = Layout::default()
         .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(20), Constraint::Percentage(80)])
+        .split(area);
+
+    // Split the color codes into two columns: the common formats and other color spaces.
+    let codes_chunks = Layout::default()
+        .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(converstion_chunks[1]);
+        .split(preview_codes_chunks[1]);
 
-    // Split the first portion of the second chunk.
-    let converstion_chunks_third_split_half = Layout::default()
+    // Split the first column into five chunks for displaying CMYK, HEX, HSL, RGB, and the named color.
+    let conversion_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(converstion_chunks_third_split[0]);
+        .constraints([
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
+        ])
+        .split(codes_chunks[0]);
+
+    // Render the color over a checkerboard, so transparency is visible.
+    let preview_title = match (
+        &app.color_converter.color,
+        &app.color_converter.ambiguous_hsl,
+    ) {
+        (Some(_), Some(_)) => " Preview (Ambiguous Input: RGB or HSL) ".to_string(),
+        (Some(color), None) if color.a < 1.0 => {
+            format!(" Preview (Alpha {:.0}%) ", color.a * 100.0)
+        }
+        _ => " Preview ".to_string(),
+    };
+    // Without truecolor, the swatch can only approximate the color.
+    let preview_title = match (&app.color_converter.color, app.truecolor) {
+        (Some(color), false) => format!(
+            "{}(ANSI 256 Approximation: {}) ",
+            preview_title,
+            color_converter::nearest_ansi256(color.r, color.g, color.b)
+        ),
+        _ => preview_title,
+    };
+    let preview_block = Block::default()
+        .title(preview_title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        );
+    let preview_area = preview_block.inner(preview_codes_chunks[0]);
+    f.render_widget(preview_block, preview_codes_chunks[0]);
+    match (
+        &app.color_converter.color,
+        &app.color_converter.ambiguous_hsl,
+    ) {
+        // Show both readings of ambiguous input side by side.
+        (Some(rgb), Some(hsl)) => {
+            let halves = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(preview_area);
+            let readings = [
+                (
+                    rgb,
+                    format!(" Read as RGB: {} (shown)", app.color_converter.hex),
+                ),
+                (
+                    hsl,
+                    format!(
+                        " As HSL: {} (use hsl: or Alt + f)",
+                        color_converter::convert_to_hex(hsl)
+                    ),
+                ),
+            ];
+            for ((color, label), half) in readings.iter().zip(halves.iter()) {
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(1), Constraint::Min(0)])
+                    .split(*half);
+                f.render_widget(Paragraph::new(label.as_str()).bold(), rows[0]);
+                color_swatch(f, color, rows[1], app.truecolor);
+            }
+        }
+        (Some(color), None) => {
+            color_swatch(f, color, preview_area, app.truecolor);
+        }
+        _ => {}
+    }
 
-    // Render the Time Only conversion result.
-    let timeonly_text = vec![Line::from(vec![Span::styled(
-        app.date_converter.time_only.to_string(),
-        Style::default().fg(Color::Green),
-    )])];
-    let timeonly = Paragraph::new(timeonly_text)
+    // Render the CMYK value.
+    let cmyk = Paragraph::new(app.color_converter.cmyk.clone())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" CMYK Color Code ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true })
+        .scroll((0, 0));
+    f.render_widget(cmyk, conversion_chunks[0]);
+
+    // Render the HEX value.
+    let hex = Paragraph::new(app.color_converter.hex.clone())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" HEX Color Code ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true })
+        .scroll((0, 0));
+    f.render_widget(hex, conversion_chunks[1]);
+
+    // Render the HSL value.
+    let hsl = Paragraph::new(app.color_converter.hsl.clone())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" HSL Color Code ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true })
+        .scroll((0, 0));
+    f.render_widget(hsl, conversion_chunks[2]);
+
+    // Render the RGB value.
+    let rgb = Paragraph::new(app.color_converter.rgb.clone())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" RGB Color Code ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true })
+        .scroll((0, 0));
+    f.render_widget(rgb, conversion_chunks[3]);
+
+    // Render the nearest named color.
+    let named_color = Paragraph::new(app.color_converter.named_color.clone())
         .style(
             Style::default()
-                .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
-        )
-        .block(
-            Block::default()
-                .title(" Time Only Conversion ")
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .padding(Padding::new(1, 1, 0, 0)),
-        )
-        .wrap(Wrap { trim: true });
-    f.render_widget(timeonly, converstion_chunks_third_split_half[0]);
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Nearest CSS Named Color ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true })
+        .scroll((0, 0));
+    f.render_widget(named_color, conversion_chunks[4]);
+
+    // Render the other color spaces, one per row, followed by whether the color fits in sRGB.
+    let converter = &app.color_converter;
+    let mut color_space_lines: Vec<Line> = [
+        ("HSV:   ", &converter.hsv),
+        ("HWB:   ", &converter.hwb),
+        ("XYZ:   ", &converter.xyz),
+        ("Lab:   ", &converter.lab),
+        ("LCH:   ", &converter.lch),
+        ("OKLab: ", &converter.oklab),
+        ("OKLCH: ", &converter.oklch),
+    ]
+    .into_iter()
+    .map(|(label, value)| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Blue)),
+            Span::styled(value.clone(), Style::default().fg(Color::Green)),
+        ])
+    })
+    .collect();
+    let gamut_color = match &converter.color {
+        Some(color) if color.clipped => Color::Yellow,
+        _ => Color::Green,
+    };
+    color_space_lines.push(Line::from(""));
+    color_space_lines.push(Line::from(vec![
+        Span::styled("Gamut: ", Style::default().fg(Color::Blue)),
+        Span::styled(converter.gamut.clone(), Style::default().fg(gamut_color)),
+    ]));
+
+    let color_spaces = Paragraph::new(color_space_lines)
+        .style(Style::default().add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .title(" Other Color Spaces ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Green))
+                .title_style(Style::default().fg(Color::Green))
+                .padding(Padding::new(1, 1, 1, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(color_spaces, codes_chunks[1]);
+}
+
+// Renders the WCAG contrast check between the text color and the background color.
+fn color_contrast(f: &mut Frame, app: &App, area: Rect) {
+    // Split the area into the text preview, the contrast results and the suggestion.
+    let contrast_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(30),
+            Constraint::Percentage(45),
+            Constraint::Percentage(25),
+        ])
+        .split(area);
+
+    let check = match &app.color_converter.contrast {
+        Ok(check) => check,
+        Err(err) => {
+            let message = Paragraph::new(err.as_str())
+                .style(
+                    Style::default()
+                        .add_modifier(Modifier::BOLD)
+                        .fg(Color::Green),
+                )
+                .block(
+                    Block::default()
+                        .title(" WCAG Contrast ")
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .padding(Padding::new(1, 1, 0, 0)),
+                )
+                .wrap(Wrap { trim: true });
+            f.render_widget(message, area);
+            return;
+        }
+    };
+
+    // Render the text over the background, next to the suggested text color if there is one.
+    let preview_block = Block::default()
+        .title(" Text Preview ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        );
+    let preview_area = preview_block.inner(contrast_chunks[0]);
+    f.render_widget(preview_block, contrast_chunks[0]);
+
+    let mut samples = vec![("Current", check.foreground)];
+    if let Some((suggestion, _)) = &check.suggestion {
+        samples.push(("Suggested", *suggestion));
+    }
+    let sample_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, samples.len() as u32);
+            samples.len()
+        ])
+        .split(preview_area);
+    for ((label, foreground), sample_area) in samples.iter().zip(sample_chunks.iter()) {
+        let style = Style::default()
+            .fg(terminal_color(
+                foreground.r,
+                foreground.g,
+                foreground.b,
+                app.truecolor,
+            ))
+            .bg(terminal_color(
+                check.background.r,
+                check.background.g,
+                check.background.b,
+                app.truecolor,
+            ));
+        let sample = Paragraph::new(vec![
+            Line::from(format!("{} Text Color", label)).add_modifier(Modifier::UNDERLINED),
+            Line::from(""),
+            Line::from("Normal text: the quick brown fox jumps over the lazy dog.")
+                .style(Style::default().remove_modifier(Modifier::BOLD)),
+            Line::from(""),
+            Line::from("LARGE OR BOLD TEXT"),
+        ])
+        .style(style.add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .padding(Padding::new(1, 1, 1, 0))
+                .style(style),
+        )
+        .wrap(Wrap { trim: true });
+        f.render_widget(sample, *sample_area);
+    }
+
+    // Render the luminances, the contrast ratio and whether each WCAG level passes.
+    let label = |text: &str| Span::styled(text.to_string(), Style::default().fg(Color::Blue));
+    let value = |text: String| Span::styled(text, Style::default().fg(Color::Green));
+    let mut result_lines = vec![
+        Line::from(vec![
+            label("Text Color:       "),
+            value(format!(
+                "{} (luminance {:.3})",
+                color_converter::convert_to_hex(&check.foreground),
+                check.foreground_luminance
+            )),
+        ]),
+        Line::from(vec![
+            label("Background Color: "),
+            value(format!(
+                "{} (luminance {:.3})",
+                color_converter::convert_to_hex(&check.background),
+                check.background_luminance
+            )),
+        ]),
+        Line::from(vec![
+            label("Contrast Ratio:   "),
+            value(format!("{:.2}:1", check.ratio)),
+        ]),
+        Line::from(""),
+    ];
+    for (level, required) in color_converter::WCAG_LEVELS {
+        let (result, color) = if check.ratio >= required {
+            ("✔ Pass", Color::Green)
+        } else {
+            ("✘ Fail", Color::Red)
+        };
+        result_lines.push(Line::from(vec![
+            label(&format!("{:<24}", format!("{} ({}:1)", level, required))),
+            Span::styled(format!(" {}", result), Style::default().fg(color)),
+        ]));
+    }
+
+    let results = Paragraph::new(result_lines)
+        .style(Style::default().add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .title(" WCAG Contrast ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Green))
+                .title_style(Style::default().fg(Color::Green))
+                .padding(Padding::new(1, 1, 1, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(results, contrast_chunks[1]);
+
+    // Render the nearest text color passing AA for normal text.
+    let suggestion_text = match &check.suggestion {
+        Some((suggestion, ratio)) => format!(
+            "{} ({:.2}:1) keeps the hue and saturation of the text color, and passes AA for normal text.",
+            color_converter::convert_to_hex(suggestion),
+            ratio
+        ),
+        None if check.ratio >= 4.5 => "The text color already passes AA for normal text.".to_string(),
+        None => "No lightness of the text color passes AA on this background.".to_string(),
+    };
+    let suggestion = Paragraph::new(suggestion_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Nearest Passing Text Color ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(suggestion, contrast_chunks[2]);
+}
+
+// Renders the shades and color schemes generated from the color, and a preview of the export.
+fn color_palette(f: &mut Frame, app: &App, area: Rect) {
+    // Split the area into the shades, the color schemes and the export preview.
+    let palette_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(20),
+            Constraint::Percentage(35),
+            Constraint::Percentage(45),
+        ])
+        .split(area);
+
+    let palette = match &app.color_converter.palette {
+        Some(palette) => palette,
+        None => {
+            let message = Paragraph::new(app.color_converter.hex.as_str())
+                .style(
+                    Style::default()
+                        .add_modifier(Modifier::BOLD)
+                        .fg(Color::Green),
+                )
+                .block(
+                    Block::default()
+                        .title(" Palette ")
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .padding(Padding::new(1, 1, 0, 0)),
+                )
+                .wrap(Wrap { trim: true });
+            f.render_widget(message, area);
+            return;
+        }
+    };
+
+    // Render the shades side by side, from the lightest to the darkest.
+    let shades_block = Block::default()
+        .title(" Shades (50-900) ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        );
+    let shades_area = shades_block.inner(palette_chunks[0]);
+    f.render_widget(shades_block, palette_chunks[0]);
+    let shade_cells = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, palette.shades.len() as u32);
+            palette.shades.len()
+        ])
+        .split(shades_area);
+    for ((shade, color), cell) in palette.shades.iter().zip(shade_cells.iter()) {
+        color_cell(
+            f,
+            color,
+            vec![shade.to_string(), color_converter::convert_to_hex(color)],
+            *cell,
+            app.truecolor,
+        );
+    }
+
+    // Render each color scheme on a row, after its name.
+    let schemes_block = Block::default()
+        .title(" Color Schemes ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        );
+    let schemes_area = schemes_block.inner(palette_chunks[1]);
+    f.render_widget(schemes_block, palette_chunks[1]);
+    let scheme_rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![
+            Constraint::Ratio(1, palette.schemes.len() as u32);
+            palette.schemes.len()
+        ])
+        .split(schemes_area);
+    for ((name, colors), row) in palette.schemes.iter().zip(scheme_rows.iter()) {
+        let row_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(22), Constraint::Min(0)])
+            .split(*row);
+        let label = Paragraph::new(format!(" {}", name))
+            .style(Style::default().fg(Color::Blue))
+            .block(Block::default().padding(Padding::new(
+                0,
+                0,
+                row.height.saturating_sub(1) / 2,
+                0,
+            )));
+        f.render_widget(label, row_chunks[0]);
+
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Ratio(1, colors.len() as u32);
+                colors.len()
+            ])
+            .split(row_chunks[1]);
+        for (color, cell) in colors.iter().zip(cells.iter()) {
+            color_cell(
+                f,
+                color,
+                vec![color_converter::convert_to_hex(color)],
+                *cell,
+                app.truecolor,
+            );
+        }
+    }
+
+    // Render the palette as it will be exported.
+    let export = Paragraph::new(app.color_converter.palette_export())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(format!(
+                    " Export Preview: {} ",
+                    app.color_converter.palette_format.label()
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        );
+    f.render_widget(export, palette_chunks[2]);
+}
+
+// Renders the blend of two colors, and the gradient between them as a strip of swatches.
+fn color_mix(f: &mut Frame, app: &App, area: Rect) {
+    let mix = match &app.color_converter.mix {
+        Ok(mix) => mix,
+        Err(err) => {
+            let message = Paragraph::new(err.as_str())
+                .style(
+                    Style::default()
+                        .add_modifier(Modifier::BOLD)
+                        .fg(Color::Green),
+                )
+                .block(
+                    Block::default()
+                        .title(" Mix ")
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .padding(Padding::new(1, 1, 0, 0)),
+                )
+                .wrap(Wrap { trim: true });
+            f.render_widget(message, area);
+            return;
+        }
+    };
+
+    // Split the area into the blend, the gradient and the export preview.
+    let mix_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+        ])
+        .split(area);
+
+    // Render the blend between both colors.
+    let blend_block = Block::default()
+        .title(format!(" Mix ({}) ", app.color_converter.mix_space.label()))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        );
+    let blend_area = blend_block.inner(mix_chunks[0]);
+    f.render_widget(blend_block, mix_chunks[0]);
+    let blend_cells = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(blend_area);
+    let ratio = app.color_converter.mix_ratio;
+    for ((label, color), cell) in [
+        (format!("From ({}%)", 100 - ratio), &mix.from),
+        ("Blend".to_string(), &mix.blend),
+        (format!("To ({}%)", ratio), &mix.to),
+    ]
+    .into_iter()
+    .zip(blend_cells.iter())
+    {
+        color_cell(
+            f,
+            color,
+            vec![label, color_converter::convert_to_hex(color)],
+            *cell,
+            app.truecolor,
+        );
+    }
+
+    // Render the gradient as a strip of swatches, from the first to the second color.
+    let gradient_block = Block::default()
+        .title(format!(" Gradient ({} Steps) ", mix.gradient.len()))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        );
+    let gradient_area = gradient_block.inner(mix_chunks[1]);
+    f.render_widget(gradient_block, mix_chunks[1]);
+    let gradient_cells = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, mix.gradient.len() as u32);
+            mix.gradient.len()
+        ])
+        .split(gradient_area);
+    for (color, cell) in mix.gradient.iter().zip(gradient_cells.iter()) {
+        color_cell(
+            f,
+            color,
+            vec![color_converter::convert_to_hex(color)],
+            *cell,
+            app.truecolor,
+        );
+    }
+
+    // Render the gradient as it will be exported.
+    let hex_list: Vec<String> = mix
+        .gradient
+        .iter()
+        .map(color_converter::convert_to_hex)
+        .collect();
+    let export = Paragraph::new(vec![
+        Line::from(vec![Span::styled(
+            "CSS:",
+            Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(format!("{};", app.color_converter.gradient_css())),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "HEX Codes:",
+            Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(hex_list.join(", ")),
+    ])
+    .style(
+        Style::default()
+            .add_modifier(Modifier::BOLD)
+            .fg(Color::Green),
+    )
+    .block(
+        Block::default()
+            .title(" Export Preview ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .padding(Padding::new(1, 1, 0, 0)),
+    )
+    .wrap(Wrap { trim: true });
+    f.render_widget(export, mix_chunks[2]);
+}
+
+// Renders the dominant colors of an image, with their pixel share and all their formats.
+fn color_image(f: &mut Frame, app: &App, area: Rect) {
+    let colors = match &app.color_converter.image_colors {
+        Ok(colors) => colors,
+        Err(err) => {
+            let message = Paragraph::new(err.as_str())
+                .style(
+                    Style::default()
+                        .add_modifier(Modifier::BOLD)
+                        .fg(Color::Green),
+                )
+                .block(
+                    Block::default()
+                        .title(" Image Palette ")
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .padding(Padding::new(1, 1, 0, 0)),
+                )
+                .wrap(Wrap { trim: true });
+            f.render_widget(message, area);
+            return;
+        }
+    };
+
+    // Split the area into the dominant colors and their formats.
+    let image_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
+        .split(area);
+
+    // Render the dominant colors side by side, from the most to the least common.
+    let colors_block = Block::default()
+        .title(format!(" Dominant Colors ({}) ", colors.len()))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        );
+    let colors_area = colors_block.inner(image_chunks[0]);
+    f.render_widget(colors_block, image_chunks[0]);
+    let color_cells = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, colors.len() as u32);
+            colors.len()
+        ])
+        .split(colors_area);
+    for (dominant, cell) in colors.iter().zip(color_cells.iter()) {
+        color_cell(
+            f,
+            &dominant.color,
+            vec![
+                color_converter::convert_to_hex(&dominant.color),
+                format!("{:.1}%", dominant.share * 100.0),
+            ],
+            *cell,
+            app.truecolor,
+        );
+    }
+
+    // List every dominant color in all formats, after a swatch of it.
+    let mut lines = Vec::new();
+    for (index, dominant) in colors.iter().enumerate() {
+        let color = &dominant.color;
+        let formats = color_converter::color_formats(color);
+        lines.push(Line::from(vec![
+            Span::styled(
+                "    ",
+                Style::default().bg(terminal_color(color.r, color.g, color.b, app.truecolor)),
+            ),
+            Span::styled(
+                format!(
+                    " Color {} ({:.1}% of pixels)",
+                    index + 1,
+                    dominant.share * 100.0
+                ),
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        // Split the formats over four lines, three formats per line.
+        for row in formats.chunks(3) {
+            let mut spans = Vec::new();
+            for (label, value) in row {
+                spans.push(Span::styled(
+                    format!("  {}: ", label),
+                    Style::default().fg(Color::Blue),
+                ));
+                spans.push(Span::raw(value.clone()));
+            }
+            lines.push(Line::from(spans));
+        }
+        lines.push(Line::from(""));
+    }
+    let formats = Paragraph::new(lines)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Color Formats ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(formats, image_chunks[1]);
+}
+
+// Renders how the colors, their contrast and a palette appear with color vision deficiencies.
+fn color_blindness(f: &mut Frame, app: &App, area: Rect) {
+    let (color, palette) = match (&app.color_converter.color, &app.color_converter.palette) {
+        (Some(color), Some(palette)) => (color, palette),
+        _ => {
+            let message = Paragraph::new(app.color_converter.hex.as_str())
+                .style(
+                    Style::default()
+                        .add_modifier(Modifier::BOLD)
+                        .fg(Color::Green),
+                )
+                .block(
+                    Block::default()
+                        .title(" Color Blindness Simulation ")
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .padding(Padding::new(1, 1, 0, 0)),
+                )
+                .wrap(Wrap { trim: true });
+            f.render_widget(message, area);
+            return;
+        }
+    };
+    let check = app.color_converter.contrast.as_ref().ok();
+
+    // Split the area into the simulated colors, their contrast when there is a background
+    // color, and the simulated palette.
+    let constraints = if check.is_some() {
+        vec![
+            Constraint::Percentage(35),
+            Constraint::Percentage(25),
+            Constraint::Percentage(40),
+        ]
+    } else {
+        vec![Constraint::Percentage(25), Constraint::Percentage(75)]
+    };
+    let simulation_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area);
+
+    // Render each color as seen with normal vision and with each deficiency.
+    let mut colors = vec![("Color", *color)];
+    if let Some(check) = check {
+        colors = vec![
+            ("Text Color", check.foreground),
+            ("Background", check.background),
+        ];
+    }
+    let colors_block = Block::default()
+        .title(" Colors as Seen with Each Deficiency ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        );
+    let colors_area = colors_block.inner(simulation_chunks[0]);
+    f.render_widget(colors_block, simulation_chunks[0]);
+    let color_rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![
+            Constraint::Ratio(1, colors.len() as u32);
+            colors.len()
+        ])
+        .split(colors_area);
+    for ((name, color), row) in colors.iter().zip(color_rows.iter()) {
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, 5); 5])
+            .split(*row);
+        color_cell(
+            f,
+            color,
+            vec![name.to_string(), color_converter::convert_to_hex(color)],
+            cells[0],
+            app.truecolor,
+        );
+        for (deficiency, cell) in color_converter::Deficiency::ALL
+            .iter()
+            .zip(cells.iter().skip(1))
+        {
+            let simulated = deficiency.simulate(color);
+            color_cell(
+                f,
+                &simulated,
+                vec![
+                    deficiency.label().to_string(),
+                    color_converter::convert_to_hex(&simulated),
+                ],
+                *cell,
+                app.truecolor,
+            );
+        }
+    }
+
+    // Render the contrast ratio as seen with normal vision and with each deficiency.
+    if let Some(check) = check {
+        let ratios = std::iter::once(("Normal Vision".to_string(), check.ratio)).chain(
+            color_converter::Deficiency::ALL.iter().map(|deficiency| {
+                (
+                    format!("{} ({})", deficiency.label(), deficiency.description()),
+                    check.simulated_ratio(*deficiency),
+                )
+            }),
+        );
+        let contrast_lines: Vec<Line> = ratios
+            .map(|(vision, ratio)| {
+                let (result, color) = if ratio >= 4.5 {
+                    ("✔ Passes AA", Color::Green)
+                } else if ratio >= 3.0 {
+                    ("✔ Passes AA for Large Text Only", Color::Yellow)
+                } else {
+                    ("✘ Fails AA", Color::Red)
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:<36}", vision), Style::default().fg(Color::Blue)),
+                    Span::styled(
+                        format!("{:>6.2}:1  ", ratio),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(result, Style::default().fg(color)),
+                ])
+            })
+            .collect();
+        let contrast = Paragraph::new(contrast_lines)
+            .style(Style::default().add_modifier(Modifier::BOLD))
+            .block(
+                Block::default()
+                    .title(" Contrast Ratio with Each Deficiency ")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(Color::Green))
+                    .title_style(Style::default().fg(Color::Green))
+                    .padding(Padding::new(1, 1, 0, 0)),
+            );
+        f.render_widget(contrast, simulation_chunks[1]);
+    }
+
+    // Render the complementary and triadic colors of the palette with each kind of vision,
+    // to check they can still be told apart.
+    let palette_colors: Vec<color_converter::Color> = std::iter::once(*color)
+        .chain(
+            palette
+                .schemes
+                .iter()
+                .filter(|(name, _)| *name == "Complementary" || *name == "Triadic")
+                .flat_map(|(_, colors)| colors.iter().skip(1).copied()),
+        )
+        .collect();
+    let palette_block = Block::default()
+        .title(" Palette (Complementary and Triadic Colors) with Each Deficiency ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        );
+    let palette_area = palette_block.inner(simulation_chunks[simulation_chunks.len() - 1]);
+    f.render_widget(
+        palette_block,
+        simulation_chunks[simulation_chunks.len() - 1],
+    );
+    let visions: Vec<(&str, Option<color_converter::Deficiency>)> =
+        std::iter::once(("Normal Vision", None))
+            .chain(
+                color_converter::Deficiency::ALL
+                    .iter()
+                    .map(|deficiency| (deficiency.label(), Some(*deficiency))),
+            )
+            .collect();
+    let palette_rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![
+            Constraint::Ratio(1, visions.len() as u32);
+            visions.len()
+        ])
+        .split(palette_area);
+    for ((vision, deficiency), row) in visions.iter().zip(palette_rows.iter()) {
+        let row_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(16), Constraint::Min(0)])
+            .split(*row);
+        let label = Paragraph::new(format!(" {}", vision))
+            .style(Style::default().fg(Color::Blue))
+            .block(Block::default().padding(Padding::new(
+                0,
+                0,
+                row.height.saturating_sub(1) / 2,
+                0,
+            )));
+        f.render_widget(label, row_chunks[0]);
+
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Ratio(1, palette_colors.len() as u32);
+                palette_colors.len()
+            ])
+            .split(row_chunks[1]);
+        for (color, cell) in palette_colors.iter().zip(cells.iter()) {
+            let seen = match deficiency {
+                Some(deficiency) => deficiency.simulate(color),
+                None => *color,
+            };
+            color_cell(
+                f,
+                &seen,
+                vec![color_converter::convert_to_hex(&seen)],
+                *cell,
+                app.truecolor,
+            );
+        }
+    }
+}
+
+// Renders a cell filled with a color, with lines of text centered over it in black or white.
+fn color_cell(
+    f: &mut Frame,
+    color: &color_converter::Color,
+    lines: Vec<String>,
+    area: Rect,
+    truecolor: bool,
+) {
+    let text_color = color_converter::readable_text_color(color);
+    let style = Style::default()
+        .bg(terminal_color(color.r, color.g, color.b, truecolor))
+        .fg(terminal_color(
+            text_color.r,
+            text_color.g,
+            text_color.b,
+            truecolor,
+        ));
+
+    // Center the lines vertically.
+    let padding = area.height.saturating_sub(lines.len() as u16) / 2;
+    let cell = Paragraph::new(lines.into_iter().map(Line::from).collect::<Vec<Line>>())
+        .style(style)
+        .alignment(Alignment::Center)
+        .block(Block::default().padding(Padding::new(0, 0, padding, 0)));
+    f.render_widget(cell, area);
+}
+
+// Returns the terminal color for an RGB color, or the nearest ANSI 256 color without truecolor.
+fn terminal_color(r: u8, g: u8, b: u8, truecolor: bool) -> Color {
+    if truecolor {
+        Color::Rgb(r, g, b)
+    } else {
+        Color::Indexed(color_converter::nearest_ansi256(r, g, b))
+    }
+}
+
+// Renders a swatch of a color blended over a checkerboard, so transparency is visible, with a
+// sample of black and white text over it. Without truecolor, the nearest ANSI 256 colors are used.
+fn color_swatch(f: &mut Frame, color: &color_converter::Color, area: Rect, truecolor: bool) {
+    // Blend a channel of the color over a checkerboard cell with its alpha.
+    let blend = |channel: u8, background: u8| -> u8 {
+        (channel as f32 * color.a + background as f32 * (1.0 - color.a)).round() as u8
+    };
+    let terminal_color = |r: u8, g: u8, b: u8| terminal_color(r, g, b, truecolor);
+
+    // Each checkerboard cell is two columns wide, so the cells look square.
+    let lines: Vec<Line> = (0..area.height)
+        .map(|row| {
+            let spans: Vec<Span> = (0..area.width.div_ceil(2))
+                .map(|column| {
+                    let background = if (row + column) % 2 == 0 { 204 } else { 153 };
+                    Span::styled(
+                        "  ",
+                        Style::default().bg(terminal_color(
+                            blend(color.r, background),
+                            blend(color.g, background),
+                            blend(color.b, background),
+                        )),
+                    )
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), area);
+
+    // Center the text sample vertically. Only the foreground is set, so the swatch shows through.
+    let sample_area = Rect {
+        y: area.y + area.height.saturating_sub(2) / 2,
+        height: area.height.min(2),
+        ..area
+    };
+    let sample = Paragraph::new(vec![
+        Line::from(Span::styled(
+            "Sample Text in Black",
+            Style::default().fg(terminal_color(0, 0, 0)),
+        )),
+        Line::from(Span::styled(
+            "Sample Text in White",
+            Style::default().fg(terminal_color(255, 255, 255)),
+        )),
+    ])
+    .bold()
+    .alignment(Alignment::Center);
+    f.render_widget(sample, sample_area);
+}
+
+fn cron_explainer(
+    f: &mut Frame,
+    app: &mut App,
+    area: Rect,
+    cron_explainer_textarea: &mut TextArea,
+) {
+    // Split the area into two chunks: input/guide area and explanation area.
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(area);
+
+    // Split the input/guide area into two chunks: input area and guide/status area.
+    let input_guide_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(20), Constraint::Percentage(80)])
+        .split(chunks[0]);
+
+    // Style the input text area.
+    cron_explainer_textarea.set_block(
+        Block::default()
+            .title(" Enter Cron Expression ")
+            .title_style(Style::default().fg(Color::Yellow).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .border_type(BorderType::Rounded)
+            .padding(Padding::new(1, 1, 0, 0)),
+    );
+
+    cron_explainer_textarea.set_style(Style::default().bold());
+
+    // Render the input text area.
+    f.render_widget(&*cron_explainer_textarea, input_guide_chunks[0]);
+
+    // Split the guide/status area into two chunks: guide area and status area.
+    let guide_status_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(80), Constraint::Percentage(20)])
+        .split(input_guide_chunks[1]);
+
+    // Create a guide text with shortcut keys, settings and examples.
+    let guide_text = vec![
+        Line::from(vec![
+            Span::styled(
+                "Esc",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "        Quit",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Tab",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "        Switch Tools",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + i",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Increase Number of Runs",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + d",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Decrease Number of Runs",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + z",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Toggle UTC / Local Time",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + x",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Export Explanation",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![
+            Span::styled(
+                "Exported File Path:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " export/cron.txt",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![Span::styled(
+            "Examples:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![
+            Span::styled(
+                "5 Fields:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " */15 9-17 * * MON-FRI",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "6 Fields:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 30 0 12 * * ? (seconds first)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "7 Fields:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 0 0 8 1 JAN ? 2030 (year last)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Macros:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " @hourly @daily @weekly @monthly @yearly @reboot",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Time Zone:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " CRON_TZ=+05:30 0 9 * * *",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+
+    // Render the guide text.
+    let guide = Paragraph::new(guide_text)
+        .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Red))
+        .block(
+            Block::default()
+                .title(" Cron Explainer Help ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 1, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(guide, guide_status_chunks[0]);
+
+    // Create a status block to display messages or errors.
+    let status_text = if let Some(message) = &app.cron_explainer.tools_export_message {
+        message.to_string()
+    } else {
+        "".to_string()
+    };
+
+    // Render the status block.
+    let status_block = Paragraph::new(status_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::LightMagenta),
+        )
+        .block(
+            Block::default()
+                .title(" Status ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(status_block, guide_status_chunks[1]);
+
+    // Split the explanation area into three chunks: explanation, fields and next runs.
+    let explanation_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(20),
+            Constraint::Percentage(35),
+            Constraint::Percentage(45),
+        ])
+        .split(chunks[1]);
+
+    // Render the plain English explanation.
+    let explanation = Paragraph::new(app.cron_explainer.explanation.as_str())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Explanation ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(explanation, explanation_chunks[0]);
+
+    // Render the field breakdown, without trimming so the error marker stays aligned.
+    let fields = Paragraph::new(app.cron_explainer.field_breakdown.as_str())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Cyan),
+        )
+        .block(
+            Block::default()
+                .title(" Fields ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(fields, explanation_chunks[1]);
+
+    // Render the upcoming run times.
+    let next_runs = Paragraph::new(app.cron_explainer.next_runs.as_str())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(format!(
+                    " Next {} Runs ({}) ",
+                    app.cron_explainer.run_count,
+                    app.cron_explainer.active_time_zone.label()
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        );
+    f.render_widget(next_runs, explanation_chunks[2]);
+}
+
+// Handles the UI for date converter.
+fn date_converter(
+    f: &mut Frame,
+    app: &mut App,
+    area: Rect,
+    date_converter_textarea: &mut TextArea,
+) {
+    // Split the area into two chunks: input/guide area and conversion results area.
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)].as_ref())
+        .split(area);
+
+    // Split the input/guide area into two chunks: input area and guide area.
+    let input_guide_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(20), Constraint::Percentage(80)])
+        .split(chunks[0]);
+
+    // Split the guide area into the guide and a status block.
+    let guide_status_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(input_guide_chunks[1]);
+
+    // Style the input text area.
+    let input_title = match app.date_converter.mode {
+        DateMode::Convert => " Enter Date, Optionally Followed by a Time Zone ",
+        DateMode::Arithmetic => " Enter a Date, Then a Second Date or a Duration ",
+    };
+    date_converter_textarea.set_block(
+        Block::default()
+            .title(input_title)
+            .title_style(Style::default().fg(Color::Yellow).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .border_type(BorderType::Rounded)
+            .padding(Padding::new(1, 1, 0, 0)),
+    );
+
+    date_converter_textarea.set_style(Style::default().bold());
+
+    // Render the input text area.
+    f.render_widget(&*date_converter_textarea, input_guide_chunks[0]);
+
+    // Create a guide text with supported formats, examples, and shortcut keys.
+    let mut guide_text = vec![
+        Line::from(vec![
+            Span::styled(
+                "Esc",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "        Quit",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Tab",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "        Switch Tools",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + p",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "    Timestamp Precision ({})",
+                    app.date_converter.precision.label()
+                ),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + o",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("    Date Order ({})", app.date_converter.date_order.label()),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + m",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("    Mode ({})", app.date_converter.mode.label()),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + s/r",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "  Save/Remove Custom Format ({} Saved)",
+                    app.date_converter.saved_formats.len()
+                ),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + h",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Show/Hide Format Specifiers",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + c",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Calendar (Arrows Move a Day/Week, PgUp/PgDn a Month)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + w",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    World Clock",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + x/j",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "  Export as Text/JSON",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![Span::styled(
+            "Supported Formats:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![Span::styled(
+            "Every Format Shown on the Right, YYYY/MM/DD and DD.MM.YYYY",
+            Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![Span::styled(
+            "DD/MM/YYYY or MM/DD/YYYY (Alt + o Picks Ambiguous Ones)",
+            Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![Span::styled(
+            "ISO Week (2024-W21-3) and Ordinal (2024-143) Dates, Month Names",
+            Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![Span::styled(
+            "A Time Only, Read as Today, Then Optionally a Zone or Offset",
+            Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        )]),
+    ];
+
+    // The examples depend on the mode.
+    let examples = match app.date_converter.mode {
+        DateMode::Convert => vec![
+            Line::from(vec![Span::raw("")]),
+            Line::from(vec![Span::styled(
+                "Examples:",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(vec![
+                Span::styled(
+                    "RFC 3339:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " 2024-05-22T13:00:00Z",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "RFC 2822:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " Wed, 22 May 2024 13:00:00 +0100",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "ISO 8601:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " 2024-05-22T13:00:00+01:00 or 20240522T130000+0100",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "Unix Timestamp:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " 1716382800, 1716382800.25 or 1716382800123 (ms)",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "Time Zone:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " 2024-03-10 03:30 America/New_York or 10:00 +05:30",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "Extra Lines:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " ZONES=Europe/Paris, Asia/Tokyo and FORMAT=%d %b %Y",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "Relative:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " yesterday 14:00, next friday, in 3 days or now + 90m",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+        ],
+        DateMode::Arithmetic => vec![
+            Line::from(vec![Span::raw("")]),
+            Line::from(vec![Span::styled(
+                "Date Arithmetic:",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(vec![
+                Span::styled(
+                    "Between Dates:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " 2024-01-15 on the first line, 2025-03-18 14:30 on the second",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "Add:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " 1716382800 then P45D, +P1Y2M or 45 days",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "Subtract:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " now then -PT4H30M or - 2 weeks",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "ISO 8601 Durations:",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    " P1Y2M3DT4H5M6.5S, or P2W for weeks",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+        ],
+    };
+    guide_text.extend(examples);
+
+    // Render the guide text.
+    let guide = Paragraph::new(guide_text)
+        .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Red))
+        .block(
+            Block::default()
+                .title(" Date Converter Help ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 1, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(guide, guide_status_chunks[0]);
+
+    // Create a status block to display messages or errors.
+    let status_text = if let Some(message) = &app.date_converter.tools_export_message {
+        message.to_string()
+    } else {
+        "".to_string()
+    };
+
+    // Render the status block.
+    let status_block = Paragraph::new(status_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::LightMagenta),
+        )
+        .block(
+            Block::default()
+                .title(" Status ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(status_block, guide_status_chunks[1]);
+
+    // Split the conversion results area into the conversions, the time zones and the custom formats.
+    let results_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(50),
+            Constraint::Percentage(30),
+            Constraint::Percentage(20),
+        ])
+        .split(chunks[1]);
+
+    // Render the date in each saved custom format, and the previewed one.
+    let custom_formats_text = if app.date_converter.custom_outputs.is_empty() {
+        vec![Line::from(vec![Span::styled(
+            "Add a FORMAT=%d %b %Y line to preview a format, Alt + s saves it and Alt + h lists the specifiers",
+            Style::default().fg(Color::White),
+        )])]
+    } else {
+        let width = app
+            .date_converter
+            .custom_outputs
+            .iter()
+            .map(|(label, _, _)| label.len())
+            .max()
+            .unwrap_or(0);
+        app.date_converter
+            .custom_outputs
+            .iter()
+            .map(|(label, template, output)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$}  ", label, width = width),
+                        Style::default().fg(Color::Blue),
+                    ),
+                    Span::raw(output.as_str()),
+                    Span::styled(
+                        format!("  ({})", template),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ])
+            })
+            .collect::<Vec<_>>()
+    };
+    let custom_formats = Paragraph::new(custom_formats_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Custom Formats ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(custom_formats, results_chunks[2]);
+
+    // The world clock is shown on the right of the time zones or the date arithmetic results.
+    let middle_area = if app.date_converter.show_clock {
+        let clock_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(46)])
+            .split(results_chunks[1]);
+        date_world_clock(f, app, clock_chunks[1]);
+        clock_chunks[0]
+    } else {
+        results_chunks[1]
+    };
+
+    // The calendar is shown on the left of the time zones or the date arithmetic results.
+    let zones_area = if app.date_converter.show_calendar {
+        let calendar_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(33), Constraint::Min(0)])
+            .split(middle_area);
+        date_calendar(f, app, calendar_chunks[0]);
+        calendar_chunks[1]
+    } else {
+        middle_area
+    };
+
+    // Date arithmetic replaces the time zones with its results.
+    if app.date_converter.mode == DateMode::Arithmetic {
+        date_arithmetic(f, app, zones_area);
+    } else {
+        // Render the zone the date was entered in, and the date in each of the display zones.
+        let zones_text = vec![
+            Line::from(vec![
+                Span::styled("Detected Format: ", Style::default().fg(Color::Blue)),
+                Span::raw(app.date_converter.detected_format.as_str()),
+            ]),
+            Line::from(vec![
+                Span::styled("Entered In: ", Style::default().fg(Color::Blue)),
+                Span::raw(app.date_converter.input_zone.as_str()),
+            ]),
+            Line::from(""),
+        ]
+        .into_iter()
+        .chain(
+            app.date_converter
+                .zone_times
+                .lines()
+                .map(|line| Line::from(line.to_string())),
+        )
+        .collect::<Vec<_>>();
+        let zones = Paragraph::new(zones_text)
+            .style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::Green),
+            )
+            .block(
+                Block::default()
+                    .title(" Time Zones ")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .padding(Padding::new(1, 1, 0, 0)),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(zones, zones_area);
+    }
+
+    // Split the conversion results area into two chunks.
+    let converstion_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(results_chunks[0]);
+
+    // Split the first chunk of conversion results area further.
+    let converstion_chunks_first_split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(converstion_chunks[0]);
+
+    // Split the top portion of the first chunk again.
+    let converstion_chunks_second_split = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(converstion_chunks_first_split[0]);
+
+    // Render the RFC 3339 conversion result.
+    let rfc3339_text = vec![Line::from(vec![Span::styled(
+        app.date_converter.rfc3339.to_string(),
+        Style::default().fg(Color::Green),
+    )])];
+    let rfc3339 = Paragraph::new(rfc3339_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" RFC 3339 Conversion ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(rfc3339, converstion_chunks_second_split[0]);
+
+    // Render the RFC 2822 conversion result.
+    let rfc2822_text = vec![Line::from(vec![Span::styled(
+        app.date_converter.rfc2822.to_string(),
+        Style::default().fg(Color::Green),
+    )])];
+    let rfc2822 = Paragraph::new(rfc2822_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" RFC 2822 Conversion ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(rfc2822, converstion_chunks_second_split[1]);
+
+    // Split the second chunk of the conversion results area.
+    let converstion_chunks_third_split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(converstion_chunks[1]);
+
+    // Split the first portion of the second chunk.
+    let converstion_chunks_third_split_half = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(converstion_chunks_third_split[0]);
+
+    // Render the Time Only conversion result.
+    let timeonly_text = vec![Line::from(vec![Span::styled(
+        app.date_converter.time_only.to_string(),
+        Style::default().fg(Color::Green),
+    )])];
+    let timeonly = Paragraph::new(timeonly_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Time Only Conversion ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(timeonly, converstion_chunks_third_split_half[0]);
+
+    // Render the ISO 8601 conversion result.
+    let iso8601_text = vec![Line::from(vec![Span::styled(
+        app.date_converter.iso8601.to_string(),
+        Style::default().fg(Color::Green),
+    )])];
+    let iso8601 = Paragraph::new(iso8601_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" ISO 8601 Conversion ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(iso8601, converstion_chunks_third_split_half[1]);
+
+    // Render the Unix Timestamp conversion result, in every precision.
+    let unixtimestamp_text = [
+        ("Seconds: ", &app.date_converter.unix_timestamp),
+        ("Milliseconds: ", &app.date_converter.unix_milliseconds),
+        ("Microseconds: ", &app.date_converter.unix_microseconds),
+        ("Nanoseconds: ", &app.date_converter.unix_nanoseconds),
+    ]
+    .into_iter()
+    .map(|(label, value)| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Blue)),
+            Span::styled(value.to_string(), Style::default().fg(Color::Green)),
+        ])
+    })
+    .collect::<Vec<_>>();
+    let unixtimestamp = Paragraph::new(unixtimestamp_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(match app.date_converter.detected_precision {
+                    Some(precision) => format!(" Unix Conversion (Read as {}) ", precision.label()),
+                    None => " Unix Conversion ".to_string(),
+                })
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(unixtimestamp, converstion_chunks_third_split[1]);
+
+    // Split the second portion of the first chunk.
+    let converstion_chunks_fourth_split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(converstion_chunks_first_split[1]);
+
+    // Render the Human Readable conversion result.
+    let humanreadable_text = vec![
+        Line::from(vec![Span::styled(
+            app.date_converter.human_readable.to_string(),
+            Style::default().fg(Color::Green),
+        )]),
+        Line::from(vec![
+            Span::styled("Relative: ", Style::default().fg(Color::Blue)),
+            Span::styled(
+                app.date_converter.relative.to_string(),
+                Style::default().fg(Color::Green),
+            ),
+        ]),
+    ];
+    let humanreadable = Paragraph::new(humanreadable_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Human Readable Conversion ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(humanreadable, converstion_chunks_fourth_split[0]);
+
+    // Render the Short Date conversion result.
+    let shordate_text = vec![Line::from(vec![Span::styled(
+        app.date_converter.short_date.to_string(),
+        Style::default().fg(Color::Green),
+    )])];
+    let shortdate = Paragraph::new(shordate_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Short Date Conversion ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(shortdate, converstion_chunks_fourth_split[1]);
+
+    // Render the reference of strftime specifiers over the results.
+    if app.date_converter.show_format_reference {
+        date_format_reference(f, app, chunks[1]);
+    }
+}
+
+// Renders the month of the converted date, with the date highlighted and the ISO week numbers.
+fn date_calendar(f: &mut Frame, app: &App, area: Rect) {
+    let calendar_text = match app.date_converter.calendar() {
+        Some(month) => {
+            let mut lines = vec![Line::from(Span::styled(
+                format!("{:^27}", month.title),
+                Style::default().fg(Color::Yellow),
+            ))];
+            lines.extend(
+                month
+                    .summary
+                    .iter()
+                    .map(|line| Line::from(Span::raw(format!("{:^27}", line)))),
+            );
+            lines.push(Line::from(Span::styled(
+                "Wk  Mo Tu We Th Fr Sa Su",
+                Style::default().fg(Color::Blue),
+            )));
+            for (week, days) in &month.weeks {
+                let mut spans = vec![Span::styled(
+                    format!("{:>2}", week),
+                    Style::default().fg(Color::DarkGray),
+                )];
+                for day in days {
+                    spans.push(Span::raw(" "));
+                    spans.push(match day {
+                        Some(day) if *day == month.selected => Span::styled(
+                            format!("{:>2}", day.day()),
+                            Style::default().fg(Color::Black).bg(Color::Green),
+                        ),
+                        Some(day) if *day == month.today => Span::styled(
+                            format!("{:>2}", day.day()),
+                            Style::default()
+                                .fg(Color::Yellow)
+                                .add_modifier(Modifier::UNDERLINED),
+                        ),
+                        Some(day) => Span::styled(
+                            format!("{:>2}", day.day()),
+                            Style::default().fg(Color::White),
+                        ),
+                        None => Span::raw("  "),
+                    });
+                }
+                lines.push(Line::from(spans));
+            }
+            lines
+        }
+        None => vec![Line::from(
+            "Enter a date to show its month, then use the arrow keys to move it",
+        )],
+    };
+
+    let calendar = Paragraph::new(calendar_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Calendar ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(2, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(calendar, area);
+}
+
+// Renders the strftime specifiers in two columns, with the last date converted in each of them.
+fn date_format_reference(f: &mut Frame, app: &App, area: Rect) {
+    let reference = app.date_converter.format_reference();
+    let rows = reference.len().div_ceil(2);
+    let reference_text = (0..rows)
+        .map(|row| {
+            let mut spans = Vec::new();
+            for (specifier, description, example) in [reference.get(row), reference.get(row + rows)]
+                .into_iter()
+                .flatten()
+            {
+                spans.push(Span::styled(
+                    format!("{:<6}", specifier),
+                    Style::default().fg(Color::Blue),
+                ));
+                spans.push(Span::styled(
+                    format!("{:<29}", description),
+                    Style::default().fg(Color::White),
+                ));
+                spans.push(Span::raw(format!("{:<26}", example)));
+            }
+            Line::from(spans)
+        })
+        .collect::<Vec<_>>();
+
+    let reference = Paragraph::new(reference_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Format Specifiers (Alt + h to Close) ")
+                .title_style(Style::default().fg(Color::Yellow).bold())
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow))
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 1, 0)),
+        );
+    f.render_widget(Clear, area);
+    f.render_widget(reference, area);
+}
+
+// Renders the results of date arithmetic: the duration between two dates, or a date shifted by a duration.
+fn date_arithmetic(f: &mut Frame, app: &App, area: Rect) {
+    let arithmetic_text = match &app.date_converter.arithmetic {
+        Ok(results) => {
+            let width = results
+                .iter()
+                .map(|(label, _)| label.len())
+                .max()
+                .unwrap_or(0);
+            results
+                .iter()
+                .map(|(label, value)| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:<width$}  ", label, width = width),
+                            Style::default().fg(Color::Blue),
+                        ),
+                        Span::raw(value.as_str()),
+                    ])
+                })
+                .collect::<Vec<_>>()
+        }
+        Err(err) => vec![Line::from(err.as_str())],
+    };
+    let arithmetic = Paragraph::new(arithmetic_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Date Arithmetic ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(arithmetic, area);
+}
+
+// Renders the current Unix time and the current time in each of the display zones.
+fn date_world_clock(f: &mut Frame, app: &App, area: Rect) {
+    let clock = app.date_converter.world_clock(chrono::Utc::now());
+    let width = clock
+        .iter()
+        .map(|(label, _)| label.len())
+        .max()
+        .unwrap_or(0);
+    let clock_text = clock
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, (label, value))| {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}  ", label, width = width),
+                    Style::default().fg(Color::Blue),
+                ),
+                Span::raw(value),
+            ]);
+            // A blank line separates the Unix time from the zones.
+            if index == 2 {
+                vec![Line::from(""), line]
+            } else {
+                vec![line]
+            }
+        })
+        .collect::<Vec<_>>();
+    let world_clock = Paragraph::new(clock_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" World Clock ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(world_clock, area);
+}
+
+// Handles the UI for hash generator.
+fn hash_generator(
+    f: &mut Frame,
+    app: &mut App,
+    area: Rect,
+    hash_generator_textarea: &mut TextArea,
+) {
+    // Split the area into two chunks: input/guide area and hash output area.
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(area);
+
+    // Split the input/guide area into two chunks: input area and guide/status area.
+    let input_guide_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+
+    // Style the input text area.
+    hash_generator_textarea.set_block(
+        Block::default()
+            .title(" Input ")
+            .title_style(Style::default().fg(Color::Yellow).bold())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Yellow))
+            .padding(Padding::new(1, 1, 0, 0)),
+    );
+    f.render_widget(&*hash_generator_textarea, input_guide_chunks[0]);
+
+    // Split the guide/status area into two chunks: guide area and status area.
+    let guide_status_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .split(input_guide_chunks[1]);
+
+    // Create a guide text with shortcut keys and instructions.
+    let guide_text = vec![
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![
+            Span::styled(
+                "Esc",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "       Quit",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Tab",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "       Switch Tools",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + x",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "   Export Generated Hash",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + 0-9",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " Show/Hide an Algorithm",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![Span::styled(
+            "Algorithms:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        // Shown algorithms are in green, hidden ones in gray.
+        Line::from(
+            HashAlgorithm::ALL
+                .into_iter()
+                .map(|algorithm| {
+                    let color = if app.hash_generator.shown.contains(&algorithm) {
+                        Color::Green
+                    } else {
+                        Color::DarkGray
+                    };
+                    Span::styled(
+                        format!("{} {}  ", algorithm.key(), algorithm.label()),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![
+            Span::styled(
+                "Exported File Path:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " export/hash.txt",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+
+    // Render the guide text.
+    let guide = Paragraph::new(guide_text)
+        .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Red))
+        .block(
+            Block::default()
+                .title(" Hash Generator Help ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 0, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(guide, guide_status_chunks[0]);
+
+    // Render the status block for messages and errors.
+    let status_text = if let Some(message) = &app.hash_generator.tools_export_message {
+        message.to_string()
+    } else {
+        "".to_string()
+    };
+    let status_block = Paragraph::new(status_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::LightMagenta),
+        )
+        .block(
+            Block::default()
+                .title(" Status ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(status_block, guide_status_chunks[1]);
+
+    // Split the hash output area into one chunk for each shown algorithm.
+    let shown = &app.hash_generator.shown;
+    let hash_output_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            shown
+                .iter()
+                .map(|_| Constraint::Ratio(1, shown.len() as u32))
+                .collect::<Vec<_>>(),
+        )
+        .split(chunks[1]);
+
+    // Render the hash value of each shown algorithm.
+    for (algorithm, hash_area) in shown.iter().zip(hash_output_chunks.iter()) {
+        let hash = Paragraph::new(app.hash_generator.get_hash(*algorithm))
+            .style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::Green),
+            )
+            .block(
+                Block::default()
+                    .title(format!(" {} Hash ", algorithm.label()))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .padding(Padding::new(1, 1, 0, 0)),
+            )
+            .wrap(Wrap { trim: true })
+            .scroll((0, 0));
+        f.render_widget(hash, *hash_area);
+    }
+}
+
+// Handles the UI for log decoder.
+fn log_decoder(f: &mut Frame, app: &mut App, area: Rect, log_decoder_textarea: &mut TextArea) {
+    // Split the area into two chunks: input/guide area and annotated log area.
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(area);
+
+    // Split the input/guide area into two chunks: input area and guide/status area.
+    let input_guide_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(chunks[0]);
+
+    // Style the input text area.
+    log_decoder_textarea.set_block(
+        Block::default()
+            .title(" Paste Log Lines ")
+            .title_style(Style::default().fg(Color::Yellow).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .border_type(BorderType::Rounded)
+            .padding(Padding::new(1, 1, 0, 0)),
+    );
+
+    log_decoder_textarea.set_style(Style::default().bold());
+
+    // Render the input text area.
+    f.render_widget(&*log_decoder_textarea, input_guide_chunks[0]);
+
+    // Split the guide/status area into two chunks: guide area and status area.
+    let guide_status_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(75), Constraint::Percentage(25)])
+        .split(input_guide_chunks[1]);
+
+    // Create a guide text with shortcut keys and the recognized timestamps.
+    let guide_text = vec![
+        Line::from(vec![
+            Span::styled(
+                "Esc",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "        Quit",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Tab",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "        Switch Tools",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + z",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Toggle UTC / Local Time",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + x",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Export Annotated Log",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![
+            Span::styled(
+                "Exported File Path:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " export/log.txt",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![Span::styled(
+            "Recognized Timestamps:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![
+            Span::styled(
+                "Epoch:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 1700000000, 1700000000123 (ms), 16 digits (µs), 19 digits (ns)",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "ISO 8601:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 2024-03-22T10:00:00.123Z",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Time Zone:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " first line TZ=+05:30",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
 
-    // Render the ISO 8601 conversion result.
-    let iso8601_text = vec![Line::from(vec![Span::styled(
-        app.date_converter.iso8601.to_string(),
-        Style::default().fg(Color::Green),
-    )])];
-    let iso8601 = Paragraph::new(iso8601_text)
-        .style(
-            Style::default()
-                .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
-        )
+    // Render the guide text.
+    let guide = Paragraph::new(guide_text)
+        .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Red))
         .block(
             Block::default()
-                .title(" ISO 8601 Conversion ")
+                .title(" Log Decoder Help ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .padding(Padding::new(1, 1, 0, 0)),
+                .padding(Padding::new(1, 1, 1, 0)),
         )
         .wrap(Wrap { trim: true });
-    f.render_widget(iso8601, converstion_chunks_third_split_half[1]);
+    f.render_widget(guide, guide_status_chunks[0]);
 
-    // Render the Unix Timestamp conversion result.
-    let unixtimestamp_text = vec![Line::from(vec![Span::styled(
-        app.date_converter.unix_timestamp.to_string(),
-        Style::default().fg(Color::Green),
-    )])];
-    let unixtimestamp = Paragraph::new(unixtimestamp_text)
+    // Create a status block to display messages or errors.
+    let status_text = if let Some(message) = &app.log_decoder.tools_export_message {
+        message.to_string()
+    } else {
+        "".to_string()
+    };
+
+    // Render the status block.
+    let status_block = Paragraph::new(status_text)
         .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
+                .fg(Color::LightMagenta),
         )
         .block(
             Block::default()
-                .title(" Unix Conversion ")
+                .title(" Status ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
         .wrap(Wrap { trim: true });
-    f.render_widget(unixtimestamp, converstion_chunks_third_split[1]);
+    f.render_widget(status_block, guide_status_chunks[1]);
 
-    // Split the second portion of the first chunk.
-    let converstion_chunks_fourth_split = Layout::default()
+    // Split the annotated log area into two chunks: summary and annotated log.
+    let log_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(converstion_chunks_first_split[1]);
+        .constraints([Constraint::Length(9), Constraint::Min(0)])
+        .split(chunks[1]);
 
-    // Render the Human Readable conversion result.
-    let humanreadable_text = vec![Line::from(vec![Span::styled(
-        app.date_converter.human_readable.to_string(),
-        Style::default().fg(Color::Green),
-    )])];
-    let humanreadable = Paragraph::new(humanreadable_text)
+    // Render the summary.
+    let summary = Paragraph::new(app.log_decoder.summary.as_str())
         .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
+                .fg(Color::Cyan),
         )
         .block(
             Block::default()
-                .title(" Human Readable Conversion ")
+                .title(" Summary ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
         .wrap(Wrap { trim: true });
-    f.render_widget(humanreadable, converstion_chunks_fourth_split[0]);
+    f.render_widget(summary, log_chunks[0]);
 
-    // Render the Short Date conversion result.
-    let shordate_text = vec![Line::from(vec![Span::styled(
-        app.date_converter.short_date.to_string(),
-        Style::default().fg(Color::Green),
-    )])];
-    let shortdate = Paragraph::new(shordate_text)
+    // Render the annotated log, without trimming so indentation in the log is kept.
+    let annotated_log = Paragraph::new(app.log_decoder.annotated_log.as_str())
         .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
@@ -1576,23 +5122,26 @@ fn date_converter(
         )
         .block(
             Block::default()
-                .title(" Short Date Conversion ")
+                .title(format!(
+                    " Annotated Log ({}) ",
+                    app.log_decoder.active_time_zone.label()
+                ))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
-        .wrap(Wrap { trim: true });
-    f.render_widget(shortdate, converstion_chunks_fourth_split[1]);
+        .wrap(Wrap { trim: false });
+    f.render_widget(annotated_log, log_chunks[1]);
 }
 
-// Handles the UI for hash generator.
-fn hash_generator(
+// Handles the UI for network calculator.
+fn network_calculator(
     f: &mut Frame,
     app: &mut App,
     area: Rect,
-    hash_generator_textarea: &mut TextArea,
+    network_calculator_textarea: &mut TextArea,
 ) {
-    // Split the area into two chunks: input/guide area and hash output area.
+    // Split the area into two chunks: input/guide area and network details area.
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
@@ -1601,30 +5150,33 @@ fn hash_generator(
     // Split the input/guide area into two chunks: input area and guide/status area.
     let input_guide_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([Constraint::Percentage(20), Constraint::Percentage(80)])
         .split(chunks[0]);
 
     // Style the input text area.
-    hash_generator_textarea.set_block(
+    network_calculator_textarea.set_block(
         Block::default()
-            .title(" Input ")
+            .title(" Enter IP Address with Prefix or Netmask ")
             .title_style(Style::default().fg(Color::Yellow).bold())
             .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(Color::Yellow))
+            .border_type(BorderType::Rounded)
             .padding(Padding::new(1, 1, 0, 0)),
     );
-    f.render_widget(&*hash_generator_textarea, input_guide_chunks[0]);
+
+    network_calculator_textarea.set_style(Style::default().bold());
+
+    // Render the input text area.
+    f.render_widget(&*network_calculator_textarea, input_guide_chunks[0]);
 
     // Split the guide/status area into two chunks: guide area and status area.
     let guide_status_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .constraints([Constraint::Percentage(80), Constraint::Percentage(20)])
         .split(input_guide_chunks[1]);
 
-    // Create a guide text with shortcut keys and instructions.
+    // Create a guide text with shortcut keys and examples.
     let guide_text = vec![
-        Line::from(vec![Span::raw("")]),
         Line::from(vec![
             Span::styled(
                 "Esc",
@@ -1633,10 +5185,9 @@ fn hash_generator(
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                "       Quit",
+                "        Quit",
                 Style::default()
                     .fg(Color::White)
-                    .add_modifier(Modifier::BOLD)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
@@ -1648,7 +5199,35 @@ fn hash_generator(
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                "       Switch Tools",
+                "        Switch Tools",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + i",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Increase Number of Subnets",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + d",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Decrease Number of Subnets",
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
@@ -1662,10 +5241,9 @@ fn hash_generator(
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                "   Export Generated Hash",
+                "    Export Network Details",
                 Style::default()
                     .fg(Color::White)
-                    .add_modifier(Modifier::BOLD)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
@@ -1678,10 +5256,58 @@ fn hash_generator(
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                " export/hash.txt",
+                " export/network.txt",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![Span::styled(
+            "Examples:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![
+            Span::styled(
+                "CIDR:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 192.168.1.10/24",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Netmask:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 10.0.0.5 255.255.0.0",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "IPv6:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " 2001:db8::1/64",
                 Style::default()
                     .fg(Color::White)
-                    .add_modifier(Modifier::BOLD)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
@@ -1692,110 +5318,113 @@ fn hash_generator(
         .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Red))
         .block(
             Block::default()
-                .title(" Hash Generator Help ")
+                .title(" Network Calculator Help ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .padding(Padding::new(1, 0, 0, 0)),
+                .padding(Padding::new(1, 1, 1, 0)),
         )
         .wrap(Wrap { trim: true });
     f.render_widget(guide, guide_status_chunks[0]);
 
-    // Render the status block for messages and errors.
-    let status_text = if let Some(message) = &app.hash_generator.tools_export_message {
-        format!("{}", message)
+    // Create a status block to display messages or errors.
+    let status_text = if let Some(message) = &app.network_calculator.tools_export_message {
+        message.to_string()
     } else {
         "".to_string()
     };
-    let status_block = Paragraph::new(status_text)
-        .style(
-            Style::default()
-                .add_modifier(Modifier::BOLD)
-                .fg(Color::LightMagenta),
-        )
-        .block(
-            Block::default()
-                .title(" Status ")
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .padding(Padding::new(1, 1, 0, 0)),
-        )
-        .wrap(Wrap { trim: true });
-    f.render_widget(status_block, guide_status_chunks[1]);
-
-    // Split the hash output area into two chunks for SHA-1/SHA-256 and SHA-384/SHA-512.
-    let hash_output_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(chunks[1]);
-
-    // Split the top chunk into SHA-1 and SHA-256.
-    let sha1_sha256_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(hash_output_chunks[0]);
 
-    // Render the SHA-1 hash value.
-    let sha1 = Paragraph::new(app.hash_generator.get_sha1())
-        .style(
-            Style::default()
-                .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
-        )
-        .block(
-            Block::default()
-                .title(" SHA-1 Hash ")
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .padding(Padding::new(1, 1, 0, 0)),
-        )
-        .wrap(Wrap { trim: true })
-        .scroll((0, 0));
-    f.render_widget(sha1, sha1_sha256_chunks[0]);
-
-    // Render the SHA-256 hash value.
-    let sha256 = Paragraph::new(app.hash_generator.get_sha256())
+    // Render the status block.
+    let status_block = Paragraph::new(status_text)
         .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
+                .fg(Color::LightMagenta),
         )
         .block(
             Block::default()
-                .title(" SHA-256 Hash ")
+                .title(" Status ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
-        .wrap(Wrap { trim: true })
-        .scroll((0, 0));
-    f.render_widget(sha256, sha1_sha256_chunks[1]);
+        .wrap(Wrap { trim: true });
+    f.render_widget(status_block, guide_status_chunks[1]);
 
-    // Split the bottom chunk into SHA-384 and SHA-512.
-    let sha384_sha512_chunks = Layout::default()
+    // Split the network details area into three chunks: details, binary and subnets.
+    // The binary block grows to fit IPv6 addresses, which take two lines each.
+    let binary_height = app.network_calculator.binary.lines().count().max(3) as u16 + 2;
+    let network_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(hash_output_chunks[1]);
+        .constraints([
+            Constraint::Length(12),
+            Constraint::Length(binary_height),
+            Constraint::Min(0),
+        ])
+        .split(chunks[1]);
+
+    // Create a line per network detail, with the label in blue and the value in green.
+    let calculator = &app.network_calculator;
+    let details_text: Vec<Line> = [
+        ("Address:      ", &calculator.address),
+        ("Network:      ", &calculator.network),
+        ("Broadcast:    ", &calculator.broadcast),
+        ("First Host:   ", &calculator.first_host),
+        ("Last Host:    ", &calculator.last_host),
+        ("Hosts:        ", &calculator.host_count),
+        ("Netmask:      ", &calculator.netmask),
+        ("Wildcard Mask:", &calculator.wildcard),
+        ("Scope:        ", &calculator.scope),
+    ]
+    .into_iter()
+    .map(|(label, value)| {
+        Line::from(vec![
+            Span::styled(
+                label,
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" {}", value),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])
+    })
+    .collect();
+
+    // Render the network details.
+    let details = Paragraph::new(details_text)
+        .block(
+            Block::default()
+                .title(" Network Details ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(details, network_chunks[0]);
 
-    // Render the SHA-384 hash value.
-    let sha384 = Paragraph::new(app.hash_generator.get_sha384())
+    // Render the binary representation, without trimming so the bits stay aligned.
+    let binary = Paragraph::new(app.network_calculator.binary.as_str())
         .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Green),
+                .fg(Color::Cyan),
         )
         .block(
             Block::default()
-                .title(" SHA-384 Hash ")
+                .title(" Binary ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
         )
-        .wrap(Wrap { trim: true })
-        .scroll((0, 0));
-    f.render_widget(sha384, sha384_sha512_chunks[0]);
+        .wrap(Wrap { trim: false });
+    f.render_widget(binary, network_chunks[1]);
 
-    // Render the SHA-512 hash value.
-    let sha512 = Paragraph::new(app.hash_generator.get_sha512())
+    // Render the subnets the network is split into.
+    let subnets = Paragraph::new(app.network_calculator.subnets.as_str())
         .style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
@@ -1803,14 +5432,15 @@ fn hash_generator(
         )
         .block(
             Block::default()
-                .title(" SHA-512 Hash ")
+                .title(format!(
+                    " Split into {} Subnets ",
+                    app.network_calculator.subnet_count
+                ))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .padding(Padding::new(1, 1, 0, 0)),
-        )
-        .wrap(Wrap { trim: true })
-        .scroll((0, 0));
-    f.render_widget(sha512, sha384_sha512_chunks[1]);
+        );
+    f.render_widget(subnets, network_chunks[2]);
 }
 
 // Handles the UI for number base conversion.
@@ -1930,7 +5560,7 @@ fn number_base_converter(
 
     // Render the status block for messages and errors.
     let status_text = if let Some(message) = &app.number_base_converter.tools_export_message {
-        format!("{}", message)
+        message.to_string()
     } else {
         "".to_string()
     };
@@ -2538,7 +6168,7 @@ fn password_generator(f: &mut Frame, app: &mut App, area: Rect) {
 
     // Render the status block for messages and errors.
     let status_text = if let Some(message) = &app.password_generator.tools_export_message {
-        format!("{}", message)
+        message.to_string()
     } else {
         "".to_string()
     };
@@ -2714,7 +6344,7 @@ fn qr_code_generator(
 
     // Render the status block for messages and errors.
     let status_text = if let Some(message) = &app.qr_code_generator.tools_export_message {
-        format!("{}", message)
+        message.to_string()
     } else {
         "".to_string()
     };
@@ -2752,6 +6382,257 @@ fn qr_code_generator(
     f.render_widget(output, chunks[1]);
 }
 
+// Handles the UI for unicode inspector.
+fn unicode_inspector(
+    f: &mut Frame,
+    app: &mut App,
+    area: Rect,
+    unicode_inspector_textarea: &mut TextArea,
+) {
+    // Split the area into two chunks: input/guide area and inspection area.
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)].as_ref())
+        .split(area);
+
+    // Split the input/guide area into two chunks: input area and guide/status area.
+    let input_guide_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
+        .split(chunks[0]);
+
+    // Style the input text area, its title shows the current mode.
+    unicode_inspector_textarea.set_block(
+        Block::default()
+            .title(format!(" {} ", app.unicode_inspector.mode.label()))
+            .title_style(Style::default().fg(Color::Yellow).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .border_type(BorderType::Rounded)
+            .padding(Padding::new(1, 1, 0, 0)),
+    );
+
+    unicode_inspector_textarea.set_style(Style::default().bold());
+
+    // Render the input text area.
+    f.render_widget(&*unicode_inspector_textarea, input_guide_chunks[0]);
+
+    // Split the guide/status area into two chunks: guide area and status area.
+    let guide_status_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(80), Constraint::Percentage(20)])
+        .split(input_guide_chunks[1]);
+
+    // Create a guide text with shortcut keys and examples.
+    let guide_text = vec![
+        Line::from(vec![
+            Span::styled(
+                "Esc",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "        Quit",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Tab",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "        Switch Tools",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + l",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Toggle Inspect / Lookup",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Alt + x",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "    Export Characters",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![
+            Span::styled(
+                "Exported File Path:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " export/unicode.txt",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![Span::raw("")]),
+        Line::from(vec![Span::styled(
+            "Lookup Examples (one per line):",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(vec![
+            Span::styled(
+                "Code Points:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " U+00E9, 0x1F600 or \\u{200B}",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Name:",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                " LATIN SMALL LETTER E WITH ACUTE",
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+
+    // Render the guide text.
+    let guide = Paragraph::new(guide_text)
+        .style(Style::default().add_modifier(Modifier::BOLD).fg(Color::Red))
+        .block(
+            Block::default()
+                .title(" Unicode Inspector Help ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 1, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(guide, guide_status_chunks[0]);
+
+    // Create a status block to display messages or errors.
+    let status_text = if let Some(message) = &app.unicode_inspector.tools_export_message {
+        message.to_string()
+    } else {
+        "".to_string()
+    };
+
+    // Render the status block.
+    let status_block = Paragraph::new(status_text)
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::LightMagenta),
+        )
+        .block(
+            Block::default()
+                .title(" Status ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(status_block, guide_status_chunks[1]);
+
+    // Split the inspection area into two chunks: summary/normalization and characters.
+    let inspection_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(10), Constraint::Min(0)])
+        .split(chunks[1]);
+
+    // Split the summary/normalization area into two chunks side by side.
+    let summary_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(inspection_chunks[0]);
+
+    // Render the summary.
+    let summary = Paragraph::new(app.unicode_inspector.summary.as_str())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Summary ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(summary, summary_chunks[0]);
+
+    // Render the normalization forms, without trimming so the code points stay aligned.
+    let normalization = Paragraph::new(app.unicode_inspector.normalization.as_str())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Cyan),
+        )
+        .block(
+            Block::default()
+                .title(" Normalization ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(normalization, summary_chunks[1]);
+
+    // Render the character table, without wrapping so the columns stay aligned.
+    let characters = Paragraph::new(app.unicode_inspector.characters.as_str())
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Green),
+        )
+        .block(
+            Block::default()
+                .title(" Characters ")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .padding(Padding::new(1, 1, 0, 0)),
+        );
+    f.render_widget(characters, inspection_chunks[1]);
+}
+
 // Handles the UI for UUID generator.
 fn uuid_generator(f: &mut Frame, app: &mut App, area: Rect) {
     // Split the area into two chunks: settings/guide area and UUID output area.
@@ -2960,7 +6841,7 @@ fn uuid_generator(f: &mut Frame, app: &mut App, area: Rect) {
 
     // Render the status block for messages and errors.
     let status_text = if let Some(message) = &app.uuid_generator.tools_export_message {
-        format!("{}", message)
+        message.to_string()
     } else {
         "".to_string()
     };
@@ -3019,3 +6900,24 @@ fn uuid_generator(f: &mut Frame, app: &mut App, area: Rect) {
         );
     f.render_widget(version_7_uuid, version4_version7_chunks[1]);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggling_a_hash_algorithm_leaves_the_input_unchanged() {
+        let mut app = App::new();
+        let mut textarea = TextArea::default();
+        hash_generator_key(&mut app, &mut textarea, '1', KeyModifiers::ALT);
+        for c in "abc".chars() {
+            hash_generator_key(&mut app, &mut textarea, c, KeyModifiers::NONE);
+        }
+
+        assert_eq!(textarea.lines(), ["abc"]);
+        assert_eq!(
+            app.hash_generator.get_hash(HashAlgorithm::Sha256),
+            HashAlgorithm::Sha256.hash(b"abc")
+        );
+    }
+}